@@ -0,0 +1,82 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::hint::black_box;
+
+use aoc2023_day1::vocabulary::Vocabulary;
+use aoc2023_day1::{
+    calc_sum_part_1, calc_sum_part_1_collect, calc_sum_part_2, calc_sum_part_2_word_scan,
+};
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+// About 4 MiB of lines mixing letters, digits and spelled-out digits, from a fixed seed.
+fn document() -> String {
+    let mut seed = 0x2023_u64;
+    let mut random = |n: u64| {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) % n
+    };
+
+    let mut document = String::new();
+    while document.len() < 4 << 20 {
+        // Mostly letters, like the puzzle input, with at least one digit somewhere in each line.
+        let digit_at = random(24);
+        for i in 0..random(16) + 24 {
+            if i == digit_at {
+                document.push(char::from(b'1' + random(9) as u8));
+            }
+            match random(8) {
+                0 => document.push_str(WORDS[random(9) as usize]),
+                1 => document.push(char::from(b'0' + random(10) as u8)),
+                _ => document.push(char::from(b'a' + random(26) as u8)),
+            }
+        }
+        document.push('\n');
+    }
+    document
+}
+
+fn part_1(c: &mut Criterion) {
+    let document = document();
+    assert_eq!(
+        calc_sum_part_1(&document),
+        calc_sum_part_1_collect(&document)
+    );
+
+    let mut group = c.benchmark_group("part_1");
+    group.throughput(Throughput::Bytes(document.len() as u64));
+    group.sample_size(10);
+    group.bench_function("bytes", |b| {
+        b.iter(|| calc_sum_part_1(black_box(&document)))
+    });
+    group.bench_function("collect", |b| {
+        b.iter(|| calc_sum_part_1_collect(black_box(&document)))
+    });
+    group.finish();
+}
+
+fn part_2(c: &mut Criterion) {
+    let document = document();
+    let english = Vocabulary::english();
+    assert_eq!(
+        calc_sum_part_2(&document, &english),
+        calc_sum_part_2_word_scan(&document, &english)
+    );
+
+    let mut group = c.benchmark_group("part_2");
+    group.throughput(Throughput::Bytes(document.len() as u64));
+    group.sample_size(10);
+    group.bench_function("automaton", |b| {
+        b.iter(|| calc_sum_part_2(black_box(&document), &english))
+    });
+    group.bench_function("word_scan", |b| {
+        b.iter(|| calc_sum_part_2_word_scan(black_box(&document), &english))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, part_1, part_2);
+criterion_main!(benches);
@@ -0,0 +1,259 @@
+// An Aho-Corasick automaton over bytes: every pattern is found in one forward pass, including
+// overlapping ones such as "eight" and "two" in "eightwo".
+pub struct Automaton {
+    // Bytes that appear in no pattern all share class 0, which keeps the table small.
+    classes: [u16; 256],
+    stride: usize,
+    // Dense transition table, `next[state * stride + class]`, with the failure links already
+    // folded in so matching never backtracks.
+    next: Vec<u32>,
+    // For each state, the first state on its failure chain (itself included) that ends a
+    // pattern, then the next one after that; `NONE` ends the chain.
+    first_output: Vec<u32>,
+    next_output: Vec<u32>,
+    // The pattern ending in each state that ends one.
+    pattern: Vec<u32>,
+    patterns: Vec<(usize, u32)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub pattern: usize,
+    pub value: u32,
+}
+
+const ROOT: u32 = 0;
+const NONE: u32 = u32::MAX;
+
+impl Automaton {
+    // Builds the automaton for `(pattern, value)` pairs. A pattern listed twice keeps the
+    // later value.
+    pub fn new<P: AsRef<[u8]>>(patterns: &[(P, u32)]) -> Automaton {
+        let mut classes = [0u16; 256];
+        let mut stride = 1;
+        for byte in patterns.iter().flat_map(|(pattern, _)| pattern.as_ref()) {
+            if classes[*byte as usize] == 0 {
+                classes[*byte as usize] = stride as u16;
+                stride += 1;
+            }
+        }
+
+        let mut next = vec![ROOT; stride];
+        let mut pattern = vec![NONE];
+        for (i, (bytes, _)) in patterns.iter().enumerate() {
+            let mut state = ROOT;
+            for byte in bytes.as_ref() {
+                let index = state as usize * stride + classes[*byte as usize] as usize;
+                if next[index] == ROOT {
+                    next[index] = pattern.len() as u32;
+                    next.extend(vec![ROOT; stride]);
+                    pattern.push(NONE);
+                }
+                state = next[index];
+            }
+            if state != ROOT {
+                pattern[state as usize] = i as u32;
+            }
+        }
+
+        // Breadth first, so a state's failure target is complete before the state itself.
+        let states = pattern.len();
+        let mut fail = vec![ROOT; states];
+        let mut first_output = vec![NONE; states];
+        let mut queue = std::collections::VecDeque::from_iter(
+            next[..stride]
+                .iter()
+                .copied()
+                .filter(|child| *child != ROOT),
+        );
+        while let Some(state) = queue.pop_front() {
+            let f = fail[state as usize];
+            first_output[state as usize] = if pattern[state as usize] != NONE {
+                state
+            } else {
+                first_output[f as usize]
+            };
+            for class in 0..stride {
+                let index = state as usize * stride + class;
+                let fallback = next[f as usize * stride + class];
+                let child = next[index];
+                if child == ROOT {
+                    next[index] = fallback;
+                } else {
+                    fail[child as usize] = fallback;
+                    queue.push_back(child);
+                }
+            }
+        }
+        let next_output = (0..states)
+            .map(|state| first_output[fail[state] as usize])
+            .collect();
+
+        Automaton {
+            classes,
+            stride,
+            next,
+            first_output,
+            next_output,
+            pattern,
+            patterns: patterns
+                .iter()
+                .map(|(bytes, value)| (bytes.as_ref().len(), *value))
+                .collect(),
+        }
+    }
+
+    // Calls `f` for every match, overlapping ones included, ordered by where they end.
+    #[inline]
+    pub fn for_each_match(&self, haystack: &[u8], mut f: impl FnMut(Match)) {
+        let mut state = ROOT;
+        for (i, byte) in haystack.iter().enumerate() {
+            let class = self.classes[*byte as usize] as usize;
+            state = self.next[state as usize * self.stride + class];
+            let mut output = self.first_output[state as usize];
+            while output != NONE {
+                f(self.make_match(output, i + 1));
+                output = self.next_output[output as usize];
+            }
+        }
+    }
+
+    pub fn find_iter(&self, haystack: &[u8]) -> Vec<Match> {
+        let mut matches = vec![];
+        self.for_each_match(haystack, |m| matches.push(m));
+        matches
+    }
+
+    // The longest of the matches starting leftmost and the longest of those ending rightmost,
+    // i.e. the first and the last of `maximal`, without collecting them.
+    pub fn first_last(&self, haystack: &[u8]) -> Option<(Match, Match)> {
+        let mut found: Option<(Match, Match)> = None;
+        self.for_each_match(haystack, |m| match &mut found {
+            None => found = Some((m, m)),
+            Some((first, last)) => {
+                if m.start < first.start || (m.start == first.start && m.end > first.end) {
+                    *first = m;
+                }
+                if m.end > last.end || (m.end == last.end && m.start < last.start) {
+                    *last = m;
+                }
+            }
+        });
+        found
+    }
+
+    // The matches that no longer match contains, ordered by start. Overlapping ones are all
+    // kept, so "eightwo" gives "eight" and "two" while "twenty-one" only gives itself.
+    pub fn maximal(&self, haystack: &[u8]) -> Vec<Match> {
+        let mut matches = self.find_iter(haystack);
+        matches.sort_by_key(|m| (m.start, std::cmp::Reverse(m.end)));
+        let mut reach = 0;
+        matches.retain(|m| {
+            let keep = m.end > reach;
+            reach = reach.max(m.end);
+            keep
+        });
+        matches
+    }
+
+    #[inline]
+    fn make_match(&self, state: u32, end: usize) -> Match {
+        let pattern = self.pattern[state as usize] as usize;
+        let (len, value) = self.patterns[pattern];
+        Match {
+            start: end - len,
+            end,
+            pattern,
+            value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(automaton: &Automaton, haystack: &str) -> Vec<(usize, u32)> {
+        automaton
+            .find_iter(haystack.as_bytes())
+            .iter()
+            .map(|m| (m.start, m.value))
+            .collect()
+    }
+
+    #[test]
+    fn find_overlapping_matches() {
+        let automaton = Automaton::new(&[("one", 1), ("two", 2), ("eight", 8), ("8", 8)]);
+        assert_eq!(values(&automaton, "eightwo"), vec![(0, 8), (4, 2)]);
+        assert_eq!(values(&automaton, "oneight"), vec![(0, 1), (2, 8)]);
+        assert_eq!(values(&automaton, "xtwone8"), vec![(1, 2), (3, 1), (6, 8)]);
+        assert!(values(&automaton, "eigh tw").is_empty());
+    }
+
+    #[test]
+    fn report_patterns_that_are_suffixes_of_others() {
+        let automaton = Automaton::new(&[("she", 1), ("he", 2), ("hers", 3), ("e", 4)]);
+        assert_eq!(
+            values(&automaton, "shers"),
+            vec![(0, 1), (1, 2), (2, 4), (1, 3)]
+        );
+    }
+
+    #[test]
+    fn first_and_last_match() {
+        let automaton = Automaton::new(&[("seven", 7), ("eve", 5), ("n", 9)]);
+        let (first, last) = automaton.first_last(b"sevening").unwrap();
+        assert_eq!((first.start, first.end, first.value), (0, 5, 7));
+        assert_eq!((last.start, last.value), (6, 9));
+        assert_eq!(automaton.first_last(b"xyz"), None);
+
+        let automaton = Automaton::new(&[("twenty", 20), ("twenty-one", 21)]);
+        let (first, last) = automaton.first_last(b"twenty-one").unwrap();
+        assert_eq!((first.value, last.value), (21, 21));
+    }
+
+    #[test]
+    fn keep_only_maximal_matches() {
+        let automaton = Automaton::new(&[
+            ("one", 1),
+            ("eight", 8),
+            ("eighty", 80),
+            ("twenty", 20),
+            ("twenty-one", 21),
+        ]);
+        let spans = |haystack: &str| {
+            automaton
+                .maximal(haystack.as_bytes())
+                .iter()
+                .map(|m| (m.start, m.value))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(spans("twenty-one"), vec![(0, 21)]);
+        assert_eq!(spans("oneighty"), vec![(0, 1), (2, 80)]);
+        assert_eq!(spans("twenty-oneight"), vec![(0, 21), (9, 8)]);
+
+        for haystack in ["twenty-one", "oneighty", "twenty-oneight", "xeightyonez"] {
+            let maximal = automaton.maximal(haystack.as_bytes());
+            let (first, last) = automaton.first_last(haystack.as_bytes()).unwrap();
+            assert_eq!((first, last), (maximal[0], *maximal.last().unwrap()));
+        }
+    }
+
+    #[test]
+    fn any_byte_can_be_part_of_a_pattern() {
+        let every_byte = (0..=255u8).collect::<Vec<_>>();
+        let automaton = Automaton::new(&[(every_byte.clone(), 1), (vec![255, 0], 2)]);
+        let mut haystack = every_byte.clone();
+        haystack.push(0);
+        assert_eq!(
+            automaton
+                .find_iter(&haystack)
+                .iter()
+                .map(|m| (m.start, m.value))
+                .collect::<Vec<_>>(),
+            vec![(0, 1), (255, 2)]
+        );
+    }
+}
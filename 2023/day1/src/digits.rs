@@ -0,0 +1,78 @@
+// Which characters count as digits: ASCII only, or any Unicode decimal digit such as '３'
+// (full-width) or '٣' (Arabic-Indic).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Digits {
+    #[default]
+    Ascii,
+    Unicode,
+}
+
+// The zero of every run of ten decimal digits (general category Nd) as of Unicode 17, sorted.
+const ZEROS: [u32; 77] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x10D40, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450,
+    0x114D0, 0x11650, 0x116C0, 0x116D0, 0x116DA, 0x11730, 0x118E0, 0x11950, 0x11BF0, 0x11C50,
+    0x11D50, 0x11DA0, 0x11DE0, 0x11F50, 0x16130, 0x16A60, 0x16AC0, 0x16B50, 0x16D70, 0x1CCF0,
+    0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0, 0x1E5F1, 0x1E950,
+    0x1FBF0,
+];
+
+impl Digits {
+    pub fn value(self, c: char) -> Option<u32> {
+        if c.is_ascii_digit() {
+            return Some(c as u32 - '0' as u32);
+        }
+        match self {
+            Digits::Ascii => None,
+            Digits::Unicode => unicode_value(c),
+        }
+    }
+
+    // Every digit character with its value.
+    pub fn chars(self) -> Vec<(char, u32)> {
+        let zeros = match self {
+            Digits::Ascii => &ZEROS[..1],
+            Digits::Unicode => &ZEROS[..],
+        };
+        zeros
+            .iter()
+            .flat_map(|zero| (0..10).map(move |d| (char::from_u32(zero + d).unwrap(), d)))
+            .collect()
+    }
+}
+
+fn unicode_value(c: char) -> Option<u32> {
+    let c = c as u32;
+    let run = ZEROS.partition_point(|zero| *zero <= c).checked_sub(1)?;
+    let value = c - ZEROS[run];
+    (value < 10).then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zeros_start_runs_of_ten_numeric_characters() {
+        assert!(ZEROS.windows(2).all(|pair| pair[0] + 10 <= pair[1]));
+        for (c, _) in Digits::Unicode.chars() {
+            assert!(c.is_numeric(), "{c:?}");
+        }
+        assert_eq!(Digits::Unicode.chars().len(), 770);
+    }
+
+    #[test]
+    fn digit_values() {
+        for (c, value) in [('7', 7), ('３', 3), ('٧', 7), ('७', 7), ('𝟘', 0), ('𑥙', 9)] {
+            assert_eq!(Digits::Unicode.value(c), Some(value), "{c:?}");
+        }
+        for c in ['²', 'Ⅻ', '½', '①', 'a', '/', ':', '\u{FF1A}'] {
+            assert_eq!(Digits::Unicode.value(c), None, "{c:?}");
+        }
+        assert_eq!(Digits::Ascii.value('7'), Some(7));
+        assert_eq!(Digits::Ascii.value('３'), None);
+        assert_eq!(Digits::Ascii.chars().len(), 10);
+    }
+}
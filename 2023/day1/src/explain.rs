@@ -0,0 +1,321 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::automaton::{Automaton, Match};
+use crate::digits::Digits;
+use crate::rule::Rule;
+use crate::vocabulary::Vocabulary;
+use crate::{digit_stream, Tokenizer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    Digit,
+    Word,
+}
+
+// A matched token; `start` and `end` are byte offsets into the line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Token {
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+    pub kind: Kind,
+    pub value: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartTrace {
+    pub first: Token,
+    pub last: Token,
+    // Every digit the part reads, in order, and what the rule makes of them; `value` is `None`
+    // when the rule does not apply, with the reason in `error`.
+    pub digits: Vec<u32>,
+    pub value: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+// How both parts read one line; a part is `None` when the line has no digit for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LineTrace {
+    pub line: usize,
+    pub text: String,
+    pub part_1: Option<PartTrace>,
+    pub part_2: Option<PartTrace>,
+    pub disagree: bool,
+}
+
+// Traces every line of `contents`, numbered from 1.
+pub fn explain<'a>(
+    contents: &'a str,
+    vocabulary: &Vocabulary,
+    rule: Rule,
+) -> impl Iterator<Item = LineTrace> + 'a {
+    let explainer = Explainer::new(vocabulary, rule);
+    contents
+        .lines()
+        .enumerate()
+        .map(move |(i, line)| explainer.line(i + 1, line))
+}
+
+// Reads lines the way `sum_part_1` and `sum_part_2` do, keeping the tokens behind each value.
+pub struct Explainer {
+    digits: Digits,
+    automaton: Automaton,
+    rule: Rule,
+}
+
+impl Explainer {
+    pub fn new(vocabulary: &Vocabulary, rule: Rule) -> Explainer {
+        Explainer {
+            digits: vocabulary.digits(),
+            automaton: vocabulary.automaton(),
+            rule,
+        }
+    }
+
+    pub fn line(&self, number: usize, line: &str) -> LineTrace {
+        let digits = self.digits;
+        let digit = |(start, c): (usize, char)| {
+            let value = digits.value(c)?;
+            Some(token(line, start, start + c.len_utf8(), value, digits))
+        };
+        let first = line.char_indices().find_map(digit);
+        let last = line.char_indices().rev().find_map(digit);
+        let part_1 = first.zip(last).map(|(first, last)| {
+            let stream = Tokenizer::Digits(digits).digits(line.as_bytes());
+            self.part(first, last, stream)
+        });
+
+        let from_match = |m: Match| token(line, m.start, m.end, m.value, digits);
+        let part_2 = self
+            .automaton
+            .first_last(line.as_bytes())
+            .map(|(first, last)| {
+                let stream = digit_stream(line.as_bytes(), &self.automaton);
+                self.part(from_match(first), from_match(last), stream)
+            });
+
+        let disagree = match (&part_1, &part_2) {
+            (Some(part_1), Some(part_2)) => part_1.value != part_2.value,
+            (None, None) => false,
+            _ => true,
+        };
+
+        LineTrace {
+            line: number,
+            text: line.to_string(),
+            part_1,
+            part_2,
+            disagree,
+        }
+    }
+
+    fn part(&self, first: Token, last: Token, digits: Vec<u32>) -> PartTrace {
+        let value = self.rule.apply(&digits);
+        PartTrace {
+            first,
+            last,
+            digits,
+            value: value.ok(),
+            error: value.err().map(|err| err.to_string()),
+        }
+    }
+}
+
+fn token(line: &str, start: usize, end: usize, value: u32, digits: Digits) -> Token {
+    let text = &line[start..end];
+    let mut chars = text.chars();
+    let kind = match (chars.next().and_then(|c| digits.value(c)), chars.next()) {
+        (Some(_), None) => Kind::Digit,
+        _ => Kind::Word,
+    };
+    Token {
+        text: text.to_string(),
+        start,
+        end,
+        kind,
+        value,
+    }
+}
+
+impl LineTrace {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match self.kind {
+            Kind::Digit => "digit",
+            Kind::Word => "word",
+        };
+        write!(f, "{:?} {kind} at {}..{}", self.text, self.start, self.end)
+    }
+}
+
+// line 2: "eightwothree4" (parts disagree)
+//   part 1: 44 from 4, first "4" digit at 12..13, last "4" digit at 12..13
+//   part 2: 84 from 8 2 3 4, first "eight" word at 0..5, last "4" digit at 12..13
+impl fmt::Display for LineTrace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {:?}", self.line, self.text)?;
+        if self.disagree {
+            write!(f, " (parts disagree)")?;
+        }
+        for (number, part) in [(1, &self.part_1), (2, &self.part_2)] {
+            let Some(part) = part else {
+                write!(f, "\n  part {number}: no digit")?;
+                continue;
+            };
+            match (&part.value, &part.error) {
+                (Some(value), _) => write!(f, "\n  part {number}: {value}")?,
+                (None, Some(error)) => write!(f, "\n  part {number}: {error}")?,
+                (None, None) => write!(f, "\n  part {number}: no value")?,
+            }
+            let digits = part.digits.iter().map(|d| d.to_string());
+            write!(
+                f,
+                " from {}, first {}, last {}",
+                digits.collect::<Vec<_>>().join(" "),
+                part.first,
+                part.last
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace(line: &str) -> LineTrace {
+        Explainer::new(&Vocabulary::english(), Rule::FirstLast).line(1, line)
+    }
+
+    #[test]
+    fn trace_overlapping_words() {
+        let trace = trace("xeightwo3");
+        let part_2 = trace.part_2.as_ref().unwrap();
+        assert_eq!(
+            part_2.first,
+            Token {
+                text: "eight".to_string(),
+                start: 1,
+                end: 6,
+                kind: Kind::Word,
+                value: 8
+            }
+        );
+        assert_eq!(
+            part_2.last,
+            Token {
+                text: "3".to_string(),
+                start: 8,
+                end: 9,
+                kind: Kind::Digit,
+                value: 3
+            }
+        );
+        assert_eq!(trace.part_1.as_ref().unwrap().value, Some(33));
+        assert_eq!(part_2.value, Some(83));
+        assert!(trace.disagree);
+
+        let trace = self::trace("oneight");
+        assert_eq!(trace.part_1, None);
+        assert_eq!(trace.part_2.as_ref().unwrap().last.text, "eight");
+        assert!(trace.disagree);
+
+        assert!(!self::trace("7abc1").disagree);
+    }
+
+    #[test]
+    fn trace_as_text_and_json() {
+        let trace = trace("eightwothree4");
+        assert_eq!(
+            trace.to_string(),
+            "line 1: \"eightwothree4\" (parts disagree)\n\
+             \x20 part 1: 44 from 4, first \"4\" digit at 12..13, last \"4\" digit at 12..13\n\
+             \x20 part 2: 84 from 8 2 3 4, first \"eight\" word at 0..5, last \"4\" digit at 12..13"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&trace.to_json()).unwrap();
+        assert_eq!(json["part_2"]["first"]["kind"], "word");
+        assert_eq!(json["part_2"]["last"]["start"], 12);
+        assert_eq!(json["part_1"]["value"], 44);
+        assert_eq!(json["part_2"]["digits"], serde_json::json!([8, 2, 3, 4]));
+        assert_eq!(json["part_2"].get("error"), None);
+        assert_eq!(json["disagree"], true);
+
+        let json: serde_json::Value = serde_json::from_str(&self::trace("abc").to_json()).unwrap();
+        assert_eq!(json["part_1"], serde_json::Value::Null);
+        assert_eq!(json["disagree"], false);
+    }
+
+    #[test]
+    fn byte_offsets_of_multibyte_words() {
+        let german = Vocabulary::builtin("de").unwrap();
+        let trace = Explainer::new(&german, Rule::FirstLast).line(1, "éfünf2");
+        let first = &trace.part_2.unwrap().first;
+        assert_eq!((first.start, first.end, first.value), (2, 7, 5));
+    }
+
+    #[test]
+    fn trace_compound_numbers() {
+        let compound = Vocabulary::compound();
+        let trace = Explainer::new(&compound, Rule::FirstLast).line(1, "forty-two3");
+        let part_2 = trace.part_2.unwrap();
+        assert_eq!(
+            (part_2.first.text.as_str(), part_2.first.value),
+            ("forty-two", 42)
+        );
+        assert_eq!(part_2.digits, vec![4, 2, 3]);
+        assert_eq!(part_2.value, Some(43));
+    }
+
+    #[test]
+    fn trace_unicode_digits() {
+        let vocabulary = Vocabulary::english().with_digits(Digits::Unicode);
+        let trace = Explainer::new(&vocabulary, Rule::FirstLast).line(1, "é３x٧two");
+        let part_1 = trace.part_1.unwrap();
+        assert_eq!(
+            part_1.first,
+            Token {
+                text: "３".to_string(),
+                start: 2,
+                end: 5,
+                kind: Kind::Digit,
+                value: 3
+            }
+        );
+        assert_eq!((part_1.last.start, part_1.last.end), (6, 8));
+        assert_eq!(part_1.value, Some(37));
+        assert_eq!(trace.part_2.unwrap().value, Some(32));
+    }
+
+    #[test]
+    fn trace_other_rules() {
+        let explainer = Explainer::new(&Vocabulary::english(), Rule::FirstK(3));
+        let trace = explainer.line(1, "4x0two");
+        assert_eq!(trace.part_1.as_ref().unwrap().value, None);
+        assert_eq!(
+            trace.part_1.as_ref().unwrap().error.as_deref(),
+            Some("fewer than 3 digits")
+        );
+        assert_eq!(trace.part_2.as_ref().unwrap().value, Some(402));
+        assert!(trace.disagree);
+        assert_eq!(
+            trace.to_string(),
+            "line 1: \"4x0two\" (parts disagree)\n\
+             \x20 part 1: fewer than 3 digits from 4 0, first \"4\" digit at 0..1, last \"0\" digit at 2..3\n\
+             \x20 part 2: 402 from 4 0 2, first \"4\" digit at 0..1, last \"two\" word at 3..6"
+        );
+
+        let explainer = Explainer::new(&Vocabulary::english(), Rule::Sum);
+        assert!(!explainer.line(1, "12x3").disagree);
+    }
+}
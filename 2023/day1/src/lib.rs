@@ -0,0 +1,576 @@
+pub mod automaton;
+pub mod digits;
+pub mod explain;
+pub mod policy;
+pub mod rule;
+pub mod stream;
+pub mod vocabulary;
+
+use automaton::Automaton;
+use digits::Digits;
+use policy::{tally, LineError, LineErrors, LineValue, Policy, Report};
+use rule::Rule;
+use vocabulary::Vocabulary;
+
+// The solvers registered with the runner: every line must have a digit, and a line without
+// one panics with its number.
+pub fn calc_sum_part_1(contents: &str) -> u64 {
+    strict(sum_part_1(
+        contents,
+        Digits::Ascii,
+        Rule::FirstLast,
+        Policy::Strict,
+    ))
+}
+
+pub fn calc_sum_part_2(contents: &str, vocabulary: &Vocabulary) -> u64 {
+    strict(sum_part_2(
+        contents,
+        vocabulary,
+        Rule::FirstLast,
+        Policy::Strict,
+    ))
+}
+
+fn strict(report: Result<Report, LineErrors>) -> u64 {
+    report.unwrap_or_else(|err| panic!("{err}")).sum
+}
+
+pub fn sum_part_1(
+    contents: &str,
+    digits: Digits,
+    rule: Rule,
+    policy: Policy,
+) -> Result<Report, LineErrors> {
+    if (digits, rule) == (Digits::Ascii, Rule::FirstLast) {
+        return tally(lines(contents.as_bytes()).map(line_value_part_1), policy);
+    }
+    sum_lines(contents, &Tokenizer::Digits(digits), rule, policy)
+}
+
+pub fn sum_part_2(
+    contents: &str,
+    vocabulary: &Vocabulary,
+    rule: Rule,
+    policy: Policy,
+) -> Result<Report, LineErrors> {
+    let tokenizer = Tokenizer::Words(Box::new(vocabulary.automaton()));
+    sum_lines(contents, &tokenizer, rule, policy)
+}
+
+// Kept out of line so that the ASCII loop in `sum_part_1` stays a tight byte scan.
+#[inline(never)]
+pub fn sum_lines(
+    contents: &str,
+    tokenizer: &Tokenizer,
+    rule: Rule,
+    policy: Policy,
+) -> Result<Report, LineErrors> {
+    tally(
+        lines(contents.as_bytes()).map(|line| tokenizer.value(line, rule)),
+        policy,
+    )
+}
+
+// What a line's digits are: its digit characters alone (part 1), or also the words of a
+// vocabulary, from `Vocabulary::automaton` (part 2).
+pub enum Tokenizer {
+    Digits(Digits),
+    Words(Box<Automaton>),
+}
+
+impl Tokenizer {
+    pub fn digits(&self, line: &[u8]) -> Vec<u32> {
+        match self {
+            Tokenizer::Digits(Digits::Ascii) => line
+                .iter()
+                .filter(|b| is_digit(b))
+                .map(|b| u32::from(b - b'0'))
+                .collect(),
+            Tokenizer::Digits(digits) => String::from_utf8_lossy(line)
+                .chars()
+                .filter_map(|c| digits.value(c))
+                .collect(),
+            Tokenizer::Words(automaton) => digit_stream(line, automaton),
+        }
+    }
+
+    // The first-last rule only needs the ends of the line, so it skips collecting the digits.
+    pub fn value(&self, line: &[u8], rule: Rule) -> LineValue {
+        match (self, rule) {
+            (Tokenizer::Digits(Digits::Ascii), Rule::FirstLast) => line_value_part_1(line),
+            (Tokenizer::Digits(Digits::Unicode), Rule::FirstLast) => {
+                line_value_part_1_unicode(line)
+            }
+            (Tokenizer::Words(automaton), Rule::FirstLast) => line_value_part_2(line, automaton),
+            _ => rule.apply(&self.digits(line)),
+        }
+    }
+}
+
+// Splits like `str::lines`, finding each newline with `memchr`.
+pub fn lines(contents: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let mut rest = contents;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        match memchr::memchr(b'\n', rest) {
+            Some(end) => {
+                let line = &rest[..end];
+                rest = &rest[end + 1..];
+                Some(line.strip_suffix(b"\r").unwrap_or(line))
+            }
+            None => Some(std::mem::take(&mut rest)),
+        }
+    })
+}
+
+// Scans in from both ends, so the bytes between the first and the last digit are never read.
+pub fn line_value_part_1(line: &[u8]) -> LineValue {
+    let first = first_digit(line).ok_or(LineError::NoDigit)?;
+    let last = last_digit(line).ok_or(LineError::NoDigit)?;
+    Ok(u64::from(first * 10 + last))
+}
+
+// Eight bytes at a time, as a u64 whose digit bytes `digit_mask` flags all at once.
+fn first_digit(line: &[u8]) -> Option<u8> {
+    let mut words = line.chunks_exact(8);
+    for word in &mut words {
+        let mask = digit_mask(u64::from_le_bytes(word.try_into().unwrap()));
+        if mask != 0 {
+            return Some(word[mask.trailing_zeros() as usize / 8] - b'0');
+        }
+    }
+    words
+        .remainder()
+        .iter()
+        .copied()
+        .find(is_digit)
+        .map(|b| b - b'0')
+}
+
+fn last_digit(line: &[u8]) -> Option<u8> {
+    let mut words = line.rchunks_exact(8);
+    for word in &mut words {
+        let mask = digit_mask(u64::from_le_bytes(word.try_into().unwrap()));
+        if mask != 0 {
+            return Some(word[7 - mask.leading_zeros() as usize / 8] - b'0');
+        }
+    }
+    words
+        .remainder()
+        .iter()
+        .copied()
+        .rfind(is_digit)
+        .map(|b| b - b'0')
+}
+
+// Any Unicode decimal digit; invalid UTF-8 is read as U+FFFD, which is not one.
+pub fn line_value_part_1_unicode(line: &[u8]) -> LineValue {
+    let line = String::from_utf8_lossy(line);
+    let value = |c| Digits::Unicode.value(c);
+    let first = line.chars().find_map(value).ok_or(LineError::NoDigit)?;
+    let last = line
+        .chars()
+        .rev()
+        .find_map(value)
+        .ok_or(LineError::NoDigit)?;
+    Ok(u64::from(first * 10 + last))
+}
+
+const ONES: u64 = u64::MAX / 255;
+
+// The high bit of every byte of `word` between b'0' and b'9', and nothing else: "hasbetween"
+// from Bit Twiddling Hacks, exact per byte since no step carries into the next byte.
+fn digit_mask(word: u64) -> u64 {
+    let low = word & (ONES * 127);
+    (ONES * (127 + u64::from(b'9' + 1)) - low)
+        & !word
+        & (low + ONES * (127 - u64::from(b'0' - 1)))
+        & (ONES * 128)
+}
+
+fn is_digit(byte: &u8) -> bool {
+    byte.wrapping_sub(b'0') < 10
+}
+
+// The original part 1: collects every digit of the line to read the first and the last. Kept as
+// the reference for the byte-level scan.
+pub fn calc_sum_part_1_collect(contents: &str) -> u64 {
+    strict(tally(
+        contents.lines().map(line_value_part_1_collect),
+        Policy::Strict,
+    ))
+}
+
+fn line_value_part_1_collect(line: &str) -> LineValue {
+    let digits = line
+        .chars()
+        .filter(|c| c.is_ascii_digit())
+        .flat_map(|c| c.to_digit(10))
+        .collect::<Vec<_>>();
+
+    match (digits.first(), digits.last()) {
+        (Some(first), Some(last)) => Ok(u64::from(first * 10 + last)),
+        _ => Err(LineError::NoDigit),
+    }
+}
+
+// `automaton` comes from `Vocabulary::automaton`.
+pub fn line_value_part_2(line: &[u8], automaton: &Automaton) -> LineValue {
+    let (first, last) = automaton.first_last(line).ok_or(LineError::NoDigit)?;
+    Ok(calibration_value(first.value, last.value))
+}
+
+// Every digit a line spells, reading its maximal matches in order (see `Automaton::maximal`):
+// "oneighty" reads 1 then 8, 0 and "sixteen" reads 1, 6 rather than 6. The first and the
+// last of these digits are what `line_value_part_2` adds up.
+pub fn digit_stream(line: &[u8], automaton: &Automaton) -> Vec<u32> {
+    automaton
+        .maximal(line)
+        .iter()
+        .flat_map(|m| digits(m.value))
+        .collect()
+}
+
+// The first digit of `first` then the last digit of `last`.
+pub fn calibration_value(first: u32, last: u32) -> u64 {
+    u64::from(leading_digit(first) * 10 + last % 10)
+}
+
+fn leading_digit(mut value: u32) -> u32 {
+    while value >= 10 {
+        value /= 10;
+    }
+    value
+}
+
+fn digits(value: u32) -> Vec<u32> {
+    value
+        .to_string()
+        .bytes()
+        .map(|b| u32::from(b - b'0'))
+        .collect()
+}
+
+// A scan without the automaton: every word is tried with `starts_with` at each character from
+// the front for the first digit, and with `ends_with` from the back for the last. Kept as the
+// reference the automaton is compared against.
+pub fn calc_sum_part_2_word_scan(contents: &str, vocabulary: &Vocabulary) -> u64 {
+    strict(tally(
+        contents
+            .lines()
+            .map(|line| parse_line_part_2(line, vocabulary)),
+        Policy::Strict,
+    ))
+}
+
+fn parse_line_part_2(line: &str, vocabulary: &Vocabulary) -> LineValue {
+    let longest = |found: &dyn Fn(&str) -> bool| {
+        vocabulary
+            .words()
+            .iter()
+            .filter(|(word, _)| found(word))
+            .max_by_key(|(word, _)| word.len())
+            .map(|(_, value)| *value)
+    };
+    let digits = vocabulary.digits();
+    let starting_at = |(i, c): (usize, char)| {
+        digits
+            .value(c)
+            .or_else(|| longest(&|word| line[i..].starts_with(word)))
+    };
+    let ending_at = |(i, c): (usize, char)| {
+        digits
+            .value(c)
+            .or_else(|| longest(&|word| line[..i + c.len_utf8()].ends_with(word)))
+    };
+
+    let first = line
+        .char_indices()
+        .find_map(starting_at)
+        .ok_or(LineError::NoDigit)?;
+    let last = line
+        .char_indices()
+        .rev()
+        .find_map(ending_at)
+        .ok_or(LineError::NoDigit)?;
+
+    Ok(calibration_value(first, last))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE_1: &str = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+    const EXAMPLE_2: &str = "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen";
+
+    #[test]
+    fn examples() {
+        assert_eq!(calc_sum_part_1(EXAMPLE_1), 142);
+        let english = Vocabulary::english();
+        assert_eq!(calc_sum_part_2(EXAMPLE_2, &english), 281);
+        assert_eq!(calc_sum_part_2_word_scan(EXAMPLE_2, &english), 281);
+    }
+
+    #[test]
+    fn overlapping_words() {
+        let english = Vocabulary::english();
+        assert_eq!(calc_sum_part_2("eightwo", &english), 82);
+        assert_eq!(calc_sum_part_2("oneight", &english), 18);
+        assert_eq!(calc_sum_part_2("twone", &english), 21);
+        assert_eq!(calc_sum_part_2("sevenine0", &english), 70);
+        assert_eq!(
+            calc_sum_part_2("nineight", &english),
+            calc_sum_part_2_word_scan("nineight", &english)
+        );
+    }
+
+    #[test]
+    fn other_vocabularies() {
+        let lines = [
+            ("fr", "xquatre5huitz", 48),
+            ("fr", "deuxneuf", 29),
+            ("de", "zweiundfünfzig", 25),
+            ("de", "achtzehn3", 83),
+            ("es", "seisdoscientos", 62),
+            ("es", "7ochodos", 72),
+        ];
+        for (locale, line, expected) in lines {
+            let vocabulary = Vocabulary::builtin(locale).unwrap();
+            assert_eq!(calc_sum_part_2(line, &vocabulary), expected, "{line}");
+            assert_eq!(
+                calc_sum_part_2_word_scan(line, &vocabulary),
+                expected,
+                "{line}"
+            );
+        }
+
+        let custom = Vocabulary::parse(
+            "un 1
+une 1
+vingt et un 1
+",
+        )
+        .unwrap();
+        assert_eq!(calc_sum_part_2("2vingt et une", &custom), 21);
+        assert_eq!(calc_sum_part_2_word_scan("2vingt et une", &custom), 21);
+    }
+
+    #[test]
+    fn lines_without_digits() {
+        let english = Vocabulary::english();
+        let contents = "1abc2\nxtwoy\n\nzzz7\n";
+
+        let err = sum_part_1(contents, Digits::Ascii, Rule::FirstLast, Policy::Strict).unwrap_err();
+        assert_eq!(err.to_string(), "line 2: no digit; line 3: no digit");
+        let err = sum_part_2(contents, &english, Rule::FirstLast, Policy::Strict).unwrap_err();
+        assert_eq!(err.to_string(), "line 3: no digit");
+
+        let report = sum_part_1(contents, Digits::Ascii, Rule::FirstLast, Policy::Skip).unwrap();
+        assert_eq!((report.sum, report.skipped), (89, vec![2, 3]));
+        let report = sum_part_2(contents, &english, Rule::FirstLast, Policy::Skip).unwrap();
+        assert_eq!((report.sum, report.skipped), (111, vec![3]));
+
+        let err =
+            sum_part_1(contents, Digits::Ascii, Rule::FirstLast, Policy::FailFast).unwrap_err();
+        assert_eq!(err.to_string(), "line 2: no digit");
+    }
+
+    #[test]
+    #[should_panic(expected = "line 2: no digit")]
+    fn registered_solver_names_the_failing_line() {
+        calc_sum_part_1("12\nab\n");
+    }
+
+    #[test]
+    fn digit_mask_flags_exactly_the_digits() {
+        for byte in 0..=255u8 {
+            for position in 0..8 {
+                let mut word = [b'a'; 8];
+                word[position] = byte;
+                let expected = if byte.is_ascii_digit() {
+                    0x80 << (position * 8)
+                } else {
+                    0
+                };
+                assert_eq!(
+                    digit_mask(u64::from_le_bytes(word)),
+                    expected,
+                    "{byte} at {position}"
+                );
+            }
+        }
+        assert_eq!(
+            digit_mask(u64::from_le_bytes(*b"0a9/:\xff5z")),
+            0x0080_0000_0080_0080
+        );
+    }
+
+    fn sum_part_1_collect(contents: &str, policy: Policy) -> Result<Report, LineErrors> {
+        tally(contents.lines().map(line_value_part_1_collect), policy)
+    }
+
+    proptest! {
+        #[test]
+        fn byte_scan_matches_collect(contents in "([a-z0-9]{0,40}(\r?\n)?){0,20}") {
+            for policy in [Policy::Strict, Policy::Skip, Policy::FailFast] {
+                prop_assert_eq!(sum_part_1(&contents, Digits::Ascii, Rule::FirstLast, policy), sum_part_1_collect(&contents, policy));
+            }
+        }
+
+        #[test]
+        fn byte_scan_matches_collect_on_any_text(contents in any::<String>()) {
+            prop_assert_eq!(
+                sum_part_1(&contents, Digits::Ascii, Rule::FirstLast, Policy::Skip),
+                sum_part_1_collect(&contents, Policy::Skip)
+            );
+        }
+
+        #[test]
+        fn split_like_str_lines(contents in "[a\r\n]{0,12}") {
+            let expected = contents.lines().map(str::as_bytes).collect::<Vec<_>>();
+            prop_assert_eq!(lines(contents.as_bytes()).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn compound_numbers() {
+        let compound = Vocabulary::compound();
+        let automaton = compound.automaton();
+        let cases = [
+            ("sixteen", vec![1, 6], 16),
+            ("forty-two", vec![4, 2], 42),
+            ("fortytwo3", vec![4, 2, 3], 43),
+            ("eightwo", vec![8, 2], 82),
+            ("oneighty", vec![1, 8, 0], 10),
+            ("one hundred", vec![1, 0, 0], 10),
+            ("ninety-nine hundred", vec![9, 9, 9, 0, 0], 90),
+            ("twenty one", vec![2, 1], 21),
+            ("x7seventeen", vec![7, 1, 7], 77),
+        ];
+        for (line, stream, value) in cases {
+            assert_eq!(digit_stream(line.as_bytes(), &automaton), stream, "{line}");
+            assert_eq!(
+                line_value_part_2(line.as_bytes(), &automaton),
+                Ok(value),
+                "{line}"
+            );
+            assert_eq!(parse_line_part_2(line, &compound), Ok(value), "{line}");
+        }
+    }
+
+    fn fragments() -> impl Strategy<Value = String> {
+        let fragment = prop::sample::select(vec![
+            "one", "two", "six", "seven", "eight", "nine", "teen", "ty", "twenty", "-", " ",
+            "hundred", "x", "3",
+        ]);
+        prop::collection::vec(fragment, 1..10).prop_map(|fragments| fragments.concat())
+    }
+
+    proptest! {
+        #[test]
+        fn compound_value_reads_the_ends_of_the_digit_stream(line in fragments()) {
+            let compound = Vocabulary::compound();
+            let automaton = compound.automaton();
+            let stream = digit_stream(line.as_bytes(), &automaton);
+            let expected = Rule::FirstLast.apply(&stream);
+            prop_assert_eq!(line_value_part_2(line.as_bytes(), &automaton), expected);
+            prop_assert_eq!(parse_line_part_2(&line, &compound), expected);
+        }
+    }
+
+    #[test]
+    fn unicode_digits() {
+        let contents = "a３b٧\none٣x\nfünf४zwei\n12";
+        let report =
+            sum_part_1(contents, Digits::Unicode, Rule::FirstLast, Policy::Strict).unwrap();
+        assert_eq!(report.sum, 37 + 33 + 44 + 12);
+        assert_eq!(
+            sum_part_1(contents, Digits::Ascii, Rule::FirstLast, Policy::Skip)
+                .unwrap()
+                .skipped,
+            vec![1, 2, 3]
+        );
+
+        let german = Vocabulary::builtin("de")
+            .unwrap()
+            .with_digits(Digits::Unicode);
+        assert_eq!(
+            sum_part_2("fünf४zwei", &german, Rule::FirstLast, Policy::Strict)
+                .unwrap()
+                .sum,
+            52
+        );
+        assert_eq!(calc_sum_part_2_word_scan("fünf४zwei\nx٣", &german), 52 + 33);
+
+        let english = Vocabulary::english().with_digits(Digits::Unicode);
+        assert_eq!(calc_sum_part_2("one٣x", &english), 13);
+        assert_eq!(calc_sum_part_2_word_scan("one٣x", &english), 13);
+        assert_eq!(line_value_part_1_unicode(b"\xff7\xfe"), Ok(77));
+    }
+
+    proptest! {
+        #[test]
+        fn unicode_mode_reads_ascii_text_the_same(contents in "[ -~\n]{0,200}") {
+            prop_assert_eq!(
+                sum_part_1(&contents, Digits::Unicode, Rule::FirstLast, Policy::Skip),
+                sum_part_1(&contents, Digits::Ascii, Rule::FirstLast, Policy::Skip)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn first_last_fast_paths_match_the_rule(line in "([a-z]|[0-9]|three|eight|٣){0,12}") {
+            let unicode = Vocabulary::english().with_digits(Digits::Unicode);
+            let tokenizers = [
+                Tokenizer::Digits(Digits::Ascii),
+                Tokenizer::Digits(Digits::Unicode),
+                Tokenizer::Words(Box::new(Vocabulary::english().automaton())),
+                Tokenizer::Words(Box::new(unicode.automaton())),
+            ];
+            for tokenizer in &tokenizers {
+                let digits = tokenizer.digits(line.as_bytes());
+                prop_assert_eq!(
+                    tokenizer.value(line.as_bytes(), Rule::FirstLast),
+                    Rule::FirstLast.apply(&digits)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn other_rules() {
+        let english = Vocabulary::english();
+        let contents = "a1b2c3\n4five6\n70";
+        let sum = |rule: Rule| {
+            let part_1 = sum_part_1(contents, Digits::Ascii, rule, Policy::Skip).unwrap();
+            let part_2 = sum_part_2(contents, &english, rule, Policy::Skip).unwrap();
+            ((part_1.sum, part_1.skipped), (part_2.sum, part_2.skipped))
+        };
+        assert_eq!(
+            sum(Rule::FirstLast),
+            ((13 + 46 + 70, vec![]), (13 + 46 + 70, vec![]))
+        );
+        assert_eq!(
+            sum(Rule::FirstK(3)),
+            ((123, vec![2, 3]), (123 + 456, vec![3]))
+        );
+        assert_eq!(
+            sum(Rule::Concat),
+            ((123 + 46 + 70, vec![]), (123 + 456 + 70, vec![]))
+        );
+        assert_eq!(
+            sum(Rule::NthFromEnd(2)),
+            ((2 + 4 + 7, vec![]), (2 + 5 + 7, vec![]))
+        );
+        assert_eq!(sum(Rule::Sum), ((6 + 10 + 7, vec![]), (6 + 15 + 7, vec![])));
+
+        let unicode = sum_part_1("٣x4", Digits::Unicode, Rule::Concat, Policy::Strict);
+        assert_eq!(unicode.unwrap().sum, 34);
+    }
+}
@@ -0,0 +1,200 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{self, BufRead, BufReader, IsTerminal, Read, Write};
+use std::process;
+
+use aoc2023_day1::digits::Digits;
+use aoc2023_day1::explain;
+use aoc2023_day1::policy::{Policy, Report};
+use aoc2023_day1::rule::Rule;
+use aoc2023_day1::stream::{self, Progress};
+use aoc2023_day1::vocabulary::Vocabulary;
+use aoc2023_day1::{sum_part_1, sum_part_2};
+
+struct Config {
+    file_path: String,
+    vocabulary: Option<String>,
+    stream: bool,
+    policy: Policy,
+    explain: Option<Format>,
+    digits: Digits,
+    rule: Rule,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+// How often the streaming mode reports progress on stderr.
+const REPORT_EVERY: u64 = 64 << 20;
+
+fn main() {
+    let config = read_input(env::args()).unwrap_or_else(|err| {
+        println!("Problem parsing arguments: {err}");
+        process::exit(1);
+    });
+
+    if let Some(format) = config.explain {
+        explain(&config, format).unwrap_or_else(|err| {
+            println!("Calculation error: {err}");
+            process::exit(1);
+        });
+        return;
+    }
+
+    let (part_1, part_2) = run(&config).unwrap_or_else(|err| {
+        println!("Calculation error: {err}");
+        process::exit(1);
+    });
+
+    println!("total = {:#?}", (part_1.sum, part_2.sum));
+    for (part, report) in [(1, part_1), (2, part_2)] {
+        if !report.skipped.is_empty() {
+            let lines = report.skipped.iter().map(|line| line.to_string());
+            println!(
+                "part {part} skipped lines: {}",
+                lines.collect::<Vec<_>>().join(", ")
+            );
+        }
+    }
+}
+
+// Usage: aoc2023-day1 <input|-> [--vocabulary <en|fr|de|es|en-compound|FILE>] [--stream]
+//     [--policy <strict|skip|fail-fast>] [--explain[=json]] [--unicode]
+//     [--rule <first-last|first:K|concat|nth-from-end:N|sum>]
+fn read_input(mut args: impl Iterator<Item = String>) -> Result<Config, &'static str> {
+    args.next();
+
+    let mut file_path = None;
+    let mut vocabulary = None;
+    let mut stream = false;
+    let mut policy = Policy::default();
+    let mut explain = None;
+    let mut digits = Digits::Ascii;
+    let mut rule = Rule::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--vocabulary" => match args.next() {
+                Some(value) => vocabulary = Some(value),
+                None => return Err("--vocabulary needs a locale or a file"),
+            },
+            "--stream" => stream = true,
+            "--policy" => match args.next().map(|value| value.parse()) {
+                Some(Ok(value)) => policy = value,
+                _ => return Err("--policy must be strict, skip or fail-fast"),
+            },
+            "--explain" => explain = Some(Format::Text),
+            "--explain=json" => explain = Some(Format::Json),
+            "--unicode" => digits = Digits::Unicode,
+            "--rule" => match args.next().map(|value| value.parse()) {
+                Some(Ok(value)) => rule = value,
+                _ => {
+                    return Err("--rule must be first-last, first:K, concat, nth-from-end:N or sum")
+                }
+            },
+            _ if file_path.is_none() => file_path = Some(arg),
+            _ => return Err("Unexpected argument"),
+        }
+    }
+
+    let file_path = match file_path {
+        Some(arg) => arg,
+        None => return Err("Didn't get a file path"),
+    };
+
+    Ok(Config {
+        file_path,
+        vocabulary,
+        stream,
+        policy,
+        explain,
+        digits,
+        rule,
+    })
+}
+
+fn run(config: &Config) -> Result<(Report, Report), Box<dyn Error>> {
+    let vocabulary = load_vocabulary(config)?;
+
+    if config.stream {
+        return run_stream(
+            open(&config.file_path)?,
+            &vocabulary,
+            config.rule,
+            config.policy,
+        );
+    }
+
+    let mut contents = String::new();
+    open(&config.file_path)?.read_to_string(&mut contents)?;
+
+    let part_1 = sum_part_1(&contents, config.digits, config.rule, config.policy)?;
+
+    let part_2 = sum_part_2(&contents, &vocabulary, config.rule, config.policy)?;
+
+    Ok((part_1, part_2))
+}
+
+fn run_stream(
+    reader: impl BufRead,
+    vocabulary: &Vocabulary,
+    rule: Rule,
+    policy: Policy,
+) -> Result<(Report, Report), Box<dyn Error>> {
+    let show_progress = io::stderr().is_terminal();
+    let report = |progress: Progress| {
+        if show_progress {
+            eprint!("\r{} MiB, {} lines", progress.bytes >> 20, progress.lines);
+        }
+    };
+
+    let totals = stream::calc_sums(reader, vocabulary, rule, policy, REPORT_EVERY, report)?;
+    if show_progress && totals.bytes >= REPORT_EVERY {
+        eprintln!();
+    }
+
+    Ok((totals.part_1, totals.part_2))
+}
+
+// Prints how both parts read each line instead of the totals, as text or as one JSON object
+// per line.
+fn explain(config: &Config, format: Format) -> Result<(), Box<dyn Error>> {
+    let vocabulary = load_vocabulary(config)?;
+
+    let mut contents = String::new();
+    open(&config.file_path)?.read_to_string(&mut contents)?;
+
+    let mut out = io::stdout().lock();
+    let mut disagreements = 0;
+    for trace in explain::explain(&contents, &vocabulary, config.rule) {
+        disagreements += usize::from(trace.disagree);
+        match format {
+            Format::Text => writeln!(out, "{trace}")?,
+            Format::Json => writeln!(out, "{}", trace.to_json())?,
+        }
+    }
+    if format == Format::Text {
+        writeln!(out, "{disagreements} lines where the parts disagree")?;
+    }
+
+    Ok(())
+}
+
+fn load_vocabulary(config: &Config) -> Result<Vocabulary, Box<dyn Error>> {
+    let vocabulary = match &config.vocabulary {
+        Some(name) => Vocabulary::load(name)?,
+        None => Vocabulary::english(),
+    };
+    Ok(vocabulary.with_digits(config.digits))
+}
+
+// `-` reads from stdin.
+fn open(file_path: &str) -> io::Result<Box<dyn BufRead>> {
+    if file_path == "-" {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+    Ok(Box::new(BufReader::new(fs::File::open(file_path)?)))
+}
@@ -0,0 +1,231 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// Why a line has no calibration value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineError {
+    NoDigit,
+    // The rule needs at least this many digits.
+    TooFewDigits(usize),
+    // The value, or the sum with it, does not fit in a u64.
+    Overflow,
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LineError::NoDigit => write!(f, "no digit"),
+            LineError::TooFewDigits(needed) => write!(f, "fewer than {needed} digits"),
+            LineError::Overflow => write!(f, "value overflows"),
+        }
+    }
+}
+
+pub type LineValue = Result<u64, LineError>;
+
+// What to do with lines that have no calibration value: `Strict` reads the whole input and then
+// fails listing every such line, `Skip` counts them as 0 and lists them in the report, and
+// `FailFast` stops at the first one.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    #[default]
+    Strict,
+    Skip,
+    FailFast,
+}
+
+impl FromStr for Policy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Policy, String> {
+        match s {
+            "strict" => Ok(Policy::Strict),
+            "skip" => Ok(Policy::Skip),
+            "fail-fast" => Ok(Policy::FailFast),
+            _ => Err(format!(
+                "unknown policy {s:?}, expected strict, skip or fail-fast"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub sum: u64,
+    // 1-based numbers of the lines counted as 0.
+    pub skipped: Vec<usize>,
+}
+
+// How many failing lines `Policy::Strict` keeps; later ones are only counted.
+pub const MAX_LINE_ERRORS: usize = 100;
+
+// The lines that failed under `Policy::Strict` or `Policy::FailFast`, 1-based, and how many
+// more failed past the first `MAX_LINE_ERRORS`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineErrors {
+    pub lines: Vec<(usize, LineError)>,
+    pub more: usize,
+}
+
+impl fmt::Display for LineErrors {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, (line, err)) in self.lines.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "line {line}: {err}")?;
+        }
+        if self.more > 0 {
+            write!(f, "; and {} more lines", self.more)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for LineErrors {}
+
+// Adds up line values one at a time under a policy.
+#[derive(Debug, Clone)]
+pub struct Tally {
+    policy: Policy,
+    report: Report,
+    errors: LineErrors,
+}
+
+impl Tally {
+    pub fn new(policy: Policy) -> Tally {
+        Tally {
+            policy,
+            report: Report::default(),
+            errors: LineErrors {
+                lines: vec![],
+                more: 0,
+            },
+        }
+    }
+
+    pub fn add(&mut self, line: usize, value: LineValue) -> Result<(), LineErrors> {
+        let sum = value.and_then(|value| {
+            self.report
+                .sum
+                .checked_add(value)
+                .ok_or(LineError::Overflow)
+        });
+        match (sum, self.policy) {
+            (Ok(sum), _) => self.report.sum = sum,
+            (Err(_), Policy::Skip) => self.report.skipped.push(line),
+            (Err(err), Policy::Strict) if self.errors.lines.len() < MAX_LINE_ERRORS => {
+                self.errors.lines.push((line, err))
+            }
+            (Err(_), Policy::Strict) => self.errors.more += 1,
+            (Err(err), Policy::FailFast) => {
+                return Err(LineErrors {
+                    lines: vec![(line, err)],
+                    more: 0,
+                })
+            }
+        }
+        Ok(())
+    }
+
+    pub fn sum(&self) -> u64 {
+        self.report.sum
+    }
+
+    pub fn finish(self) -> Result<Report, LineErrors> {
+        if self.errors.lines.is_empty() {
+            Ok(self.report)
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+// Values of `lines` under `policy`, numbering lines from 1.
+pub fn tally(lines: impl Iterator<Item = LineValue>, policy: Policy) -> Result<Report, LineErrors> {
+    let mut tally = Tally::new(policy);
+    for (i, value) in lines.enumerate() {
+        tally.add(i + 1, value)?;
+    }
+    tally.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALUES: [LineValue; 5] = [
+        Ok(12),
+        Err(LineError::NoDigit),
+        Ok(38),
+        Err(LineError::NoDigit),
+        Ok(15),
+    ];
+
+    #[test]
+    fn strict_lists_every_failing_line() {
+        let err = tally(VALUES.into_iter(), Policy::Strict).unwrap_err();
+        assert_eq!(
+            err.lines,
+            vec![(2, LineError::NoDigit), (4, LineError::NoDigit)]
+        );
+        assert_eq!(err.to_string(), "line 2: no digit; line 4: no digit");
+    }
+
+    #[test]
+    fn strict_keeps_the_first_failing_lines() {
+        let values = (0..MAX_LINE_ERRORS + 5).map(|_| Err(LineError::NoDigit));
+        let err = tally(values.chain([Ok(1)]), Policy::Strict).unwrap_err();
+        assert_eq!(err.lines.len(), MAX_LINE_ERRORS);
+        assert_eq!(
+            err.lines.last(),
+            Some(&(MAX_LINE_ERRORS, LineError::NoDigit))
+        );
+        assert_eq!(err.more, 5);
+        assert!(err.to_string().ends_with("; and 5 more lines"));
+    }
+
+    #[test]
+    fn skip_counts_failing_lines_as_zero() {
+        let report = tally(VALUES.into_iter(), Policy::Skip).unwrap();
+        assert_eq!(
+            report,
+            Report {
+                sum: 65,
+                skipped: vec![2, 4]
+            }
+        );
+    }
+
+    #[test]
+    fn fail_fast_stops_at_the_first_failing_line() {
+        let mut seen = 0;
+        let values = VALUES.into_iter().inspect(|_| seen += 1);
+        let err = tally(values, Policy::FailFast).unwrap_err();
+        assert_eq!(err.to_string(), "line 2: no digit");
+        assert_eq!(seen, 2);
+    }
+
+    #[test]
+    fn all_policies_agree_without_failing_lines() {
+        for policy in [Policy::Strict, Policy::Skip, Policy::FailFast] {
+            let values = [Ok(12), Ok(38)].into_iter();
+            assert_eq!(tally(values, policy).unwrap().sum, 50);
+        }
+    }
+
+    #[test]
+    fn overflowing_sum_fails_the_line() {
+        let values = [Ok(u64::MAX - 1), Ok(2), Ok(1)].into_iter();
+        let report = tally(values, Policy::Skip).unwrap();
+        assert_eq!((report.sum, report.skipped), (u64::MAX, vec![2]));
+    }
+
+    #[test]
+    fn parse_policy() {
+        assert_eq!("skip".parse(), Ok(Policy::Skip));
+        assert_eq!("fail-fast".parse(), Ok(Policy::FailFast));
+        assert!("lenient".parse::<Policy>().is_err());
+    }
+}
@@ -0,0 +1,126 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::policy::{LineError, LineValue};
+
+// How a line's digits, in order, combine into its calibration value.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    // The first digit then the last, the puzzle's rule.
+    #[default]
+    FirstLast,
+    // The first k digits read as a number.
+    FirstK(usize),
+    // All the digits read as a number.
+    Concat,
+    // The nth digit counting back from the last, which is 1.
+    NthFromEnd(usize),
+    Sum,
+}
+
+impl Rule {
+    pub fn apply(self, digits: &[u32]) -> LineValue {
+        if digits.is_empty() {
+            return Err(LineError::NoDigit);
+        }
+        match self {
+            Rule::FirstLast => Ok(u64::from(digits[0] * 10 + digits[digits.len() - 1])),
+            Rule::FirstK(k) => number(digits.get(..k).ok_or(LineError::TooFewDigits(k))?),
+            Rule::Concat => number(digits),
+            Rule::NthFromEnd(n) => match digits.len().checked_sub(n) {
+                Some(i) => Ok(u64::from(digits[i])),
+                None => Err(LineError::TooFewDigits(n)),
+            },
+            Rule::Sum => Ok(digits.iter().map(|d| u64::from(*d)).sum()),
+        }
+    }
+}
+
+fn number(digits: &[u32]) -> LineValue {
+    digits.iter().try_fold(0u64, |n, d| {
+        n.checked_mul(10)
+            .and_then(|n| n.checked_add(u64::from(*d)))
+            .ok_or(LineError::Overflow)
+    })
+}
+
+// `first-last`, `first:K`, `concat`, `nth-from-end:N` or `sum`.
+impl FromStr for Rule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Rule, String> {
+        let count = |n: &str| match n.parse() {
+            Ok(n) if n > 0 => Ok(n),
+            _ => Err(format!("invalid count {n:?} in rule {s:?}")),
+        };
+        match s.split_once(':') {
+            None if s == "first-last" => Ok(Rule::FirstLast),
+            None if s == "concat" => Ok(Rule::Concat),
+            None if s == "sum" => Ok(Rule::Sum),
+            Some(("first", k)) => Ok(Rule::FirstK(count(k)?)),
+            Some(("nth-from-end", n)) => Ok(Rule::NthFromEnd(count(n)?)),
+            _ => Err(format!(
+                "unknown rule {s:?}, expected first-last, first:K, concat, nth-from-end:N or sum"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::FirstLast => write!(f, "first-last"),
+            Rule::FirstK(k) => write!(f, "first:{k}"),
+            Rule::Concat => write!(f, "concat"),
+            Rule::NthFromEnd(n) => write!(f, "nth-from-end:{n}"),
+            Rule::Sum => write!(f, "sum"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_rules() {
+        let digits = [4, 0, 7, 2];
+        assert_eq!(Rule::FirstLast.apply(&digits), Ok(42));
+        assert_eq!(Rule::FirstK(3).apply(&digits), Ok(407));
+        assert_eq!(
+            Rule::FirstK(5).apply(&digits),
+            Err(LineError::TooFewDigits(5))
+        );
+        assert_eq!(Rule::Concat.apply(&digits), Ok(4072));
+        assert_eq!(Rule::NthFromEnd(1).apply(&digits), Ok(2));
+        assert_eq!(Rule::NthFromEnd(4).apply(&digits), Ok(4));
+        assert_eq!(
+            Rule::NthFromEnd(5).apply(&digits),
+            Err(LineError::TooFewDigits(5))
+        );
+        assert_eq!(Rule::Sum.apply(&digits), Ok(13));
+
+        assert_eq!(Rule::FirstLast.apply(&[7]), Ok(77));
+        assert_eq!(Rule::Concat.apply(&[0, 0, 7]), Ok(7));
+        assert_eq!(Rule::Sum.apply(&[]), Err(LineError::NoDigit));
+        assert_eq!(Rule::Concat.apply(&[9; 20]), Err(LineError::Overflow));
+        assert_eq!(Rule::Concat.apply(&[1; 19]), Ok(1_111_111_111_111_111_111));
+    }
+
+    #[test]
+    fn parse_rules() {
+        for rule in [
+            Rule::FirstLast,
+            Rule::FirstK(3),
+            Rule::Concat,
+            Rule::NthFromEnd(2),
+            Rule::Sum,
+        ] {
+            assert_eq!(rule.to_string().parse(), Ok(rule));
+        }
+        assert!("first:0".parse::<Rule>().is_err());
+        assert!("first:x".parse::<Rule>().is_err());
+        assert!("last".parse::<Rule>().is_err());
+        assert!("sum:2".parse::<Rule>().is_err());
+    }
+}
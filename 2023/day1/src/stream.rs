@@ -0,0 +1,169 @@
+use std::io::{self, BufRead};
+
+use crate::policy::{LineErrors, Policy, Report, Tally};
+use crate::rule::Rule;
+use crate::vocabulary::Vocabulary;
+use crate::Tokenizer;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    pub lines: u64,
+    pub bytes: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Totals {
+    pub part_1: Report,
+    pub part_2: Report,
+    pub lines: u64,
+    pub bytes: u64,
+}
+
+// Both parts in a single pass over `reader`, one line at a time in a reused buffer. Memory is
+// bounded by the longest line under `Policy::FailFast`, and by that plus `MAX_LINE_ERRORS`
+// failing lines under `Policy::Strict`; `Policy::Skip` still lists every skipped line. `progress`
+// is called each time another `report_every` bytes have been read. Lines that fail under
+// `policy` come back as an `InvalidData` error wrapping `LineErrors`.
+pub fn calc_sums<R: BufRead>(
+    mut reader: R,
+    vocabulary: &Vocabulary,
+    rule: Rule,
+    policy: Policy,
+    report_every: u64,
+    mut progress: impl FnMut(Progress),
+) -> io::Result<Totals> {
+    let digits = Tokenizer::Digits(vocabulary.digits());
+    let words = Tokenizer::Words(Box::new(vocabulary.automaton()));
+    let mut part_1 = Tally::new(policy);
+    let mut part_2 = Tally::new(policy);
+    let mut current = Progress::default();
+    let mut next_report = report_every;
+    let mut buffer = Vec::new();
+
+    loop {
+        buffer.clear();
+        let read = reader.read_until(b'\n', &mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        current.bytes += read as u64;
+        current.lines += 1;
+
+        let line = trim_newline(&buffer);
+        let number = current.lines as usize;
+        part_1
+            .add(number, digits.value(line, rule))
+            .map_err(invalid_data)?;
+        part_2
+            .add(number, words.value(line, rule))
+            .map_err(invalid_data)?;
+
+        if report_every > 0 && current.bytes >= next_report {
+            progress(current);
+            next_report = (current.bytes / report_every + 1) * report_every;
+        }
+    }
+
+    let (part_1, part_2) = match (part_1.finish(), part_2.finish()) {
+        (Ok(part_1), Ok(part_2)) => (part_1, part_2),
+        (Err(err), _) | (_, Err(err)) => return Err(invalid_data(err)),
+    };
+    Ok(Totals {
+        part_1,
+        part_2,
+        lines: current.lines,
+        bytes: current.bytes,
+    })
+}
+
+fn invalid_data(err: LineErrors) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+fn trim_newline(line: &[u8]) -> &[u8] {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calc_sum_part_1, calc_sum_part_2};
+
+    const EXAMPLE: &str = "two1nine\r\neightwothree4\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen\n";
+
+    fn sums(document: &str, policy: Policy) -> io::Result<Totals> {
+        calc_sums(
+            document.as_bytes(),
+            &Vocabulary::english(),
+            Rule::FirstLast,
+            policy,
+            0,
+            |_| {},
+        )
+    }
+
+    #[test]
+    fn match_the_in_memory_solvers() {
+        let english = Vocabulary::english();
+        let totals = sums(EXAMPLE, Policy::Strict).unwrap();
+        assert_eq!(totals.part_1.sum, calc_sum_part_1(EXAMPLE));
+        assert_eq!(totals.part_2.sum, calc_sum_part_2(EXAMPLE, &english));
+        assert_eq!(totals.lines, 7);
+        assert_eq!(totals.bytes, EXAMPLE.len() as u64);
+
+        let totals = sums(EXAMPLE.trim_end(), Policy::Strict).unwrap();
+        assert_eq!(totals.part_2.sum, calc_sum_part_2(EXAMPLE, &english));
+    }
+
+    #[test]
+    fn report_progress() {
+        let document = "1abc2\n".repeat(10);
+        let mut reports = vec![];
+        let totals = calc_sums(
+            document.as_bytes(),
+            &Vocabulary::english(),
+            Rule::FirstLast,
+            Policy::Strict,
+            16,
+            |progress| reports.push((progress.lines, progress.bytes)),
+        )
+        .unwrap();
+        assert_eq!(reports, vec![(3, 18), (6, 36), (8, 48)]);
+        assert_eq!((totals.part_1.sum, totals.part_2.sum), (120, 120));
+    }
+
+    #[test]
+    fn apply_the_policy_to_lines_without_digit() {
+        let document = "1\nabc\ntwo\n2\n";
+
+        let err = sums(document, Policy::Strict).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "line 2: no digit; line 3: no digit");
+
+        let err = sums(document, Policy::FailFast).unwrap_err();
+        assert_eq!(err.to_string(), "line 2: no digit");
+
+        let totals = sums(document, Policy::Skip).unwrap();
+        assert_eq!(totals.part_1.skipped, vec![2, 3]);
+        assert_eq!(totals.part_2.skipped, vec![2]);
+        assert_eq!((totals.part_1.sum, totals.part_2.sum), (33, 55));
+    }
+
+    #[test]
+    fn apply_the_rule() {
+        let totals = calc_sums(
+            "a1b2c3\n4five6\n".as_bytes(),
+            &Vocabulary::english(),
+            Rule::Concat,
+            Policy::Strict,
+            0,
+            |_| {},
+        )
+        .unwrap();
+        assert_eq!(
+            (totals.part_1.sum, totals.part_2.sum),
+            (123 + 46, 123 + 456)
+        );
+    }
+}
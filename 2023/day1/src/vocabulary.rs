@@ -0,0 +1,240 @@
+use std::error::Error;
+use std::fs;
+
+use crate::automaton::Automaton;
+use crate::digits::Digits;
+
+// The words that spell out numbers, on top of the digits themselves which are always matched.
+// A word stands for the decimal digits of its value, so "twelve" reads as 1 then 2.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+    digits: Digits,
+}
+
+const ENGLISH: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const FRENCH: [&str; 9] = [
+    "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+const GERMAN: [&str; 9] = [
+    "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+const SPANISH: [&str; 9] = [
+    "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+];
+
+const TEENS: [&str; 10] = [
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const TENS: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+pub const LOCALES: [&str; 4] = ["en", "fr", "de", "es"];
+
+// English with compound numbers up to the hundreds, see `Vocabulary::compound`.
+pub const COMPOUND: &str = "en-compound";
+
+impl Vocabulary {
+    pub fn new(words: Vec<(String, u32)>) -> Result<Vocabulary, String> {
+        if words.iter().any(|(word, _)| word.is_empty()) {
+            return Err("empty word".to_string());
+        }
+        Ok(Vocabulary {
+            words,
+            digits: Digits::Ascii,
+        })
+    }
+
+    pub fn english() -> Vocabulary {
+        Vocabulary::from_digits(&ENGLISH)
+    }
+
+    // English digits plus the teens, the tens, tens and units written as "forty-two",
+    // "forty two" or "fortytwo", and hundreds as "one hundred" or "onehundred".
+    pub fn compound() -> Vocabulary {
+        let mut words = Vocabulary::english().words;
+        words.extend((10..).zip(TEENS).map(|(n, word)| (word.to_string(), n)));
+        for (tens, ten) in (20..).step_by(10).zip(TENS) {
+            words.push((ten.to_string(), tens));
+            for (unit, one) in (1..).zip(ENGLISH) {
+                for separator in ["-", " ", ""] {
+                    words.push((format!("{ten}{separator}{one}"), tens + unit));
+                }
+            }
+        }
+        for (unit, one) in (1..).zip(ENGLISH) {
+            for separator in [" ", ""] {
+                words.push((format!("{one}{separator}hundred"), unit * 100));
+            }
+        }
+        Vocabulary {
+            words,
+            digits: Digits::Ascii,
+        }
+    }
+
+    // Built-in vocabularies by locale, see `LOCALES`, or `COMPOUND`.
+    pub fn builtin(locale: &str) -> Option<Vocabulary> {
+        if locale == COMPOUND {
+            return Some(Vocabulary::compound());
+        }
+        let words = match locale {
+            "en" => &ENGLISH,
+            "fr" => &FRENCH,
+            "de" => &GERMAN,
+            "es" => &SPANISH,
+            _ => return None,
+        };
+        Some(Vocabulary::from_digits(words))
+    }
+
+    // One word per line followed by its value, e.g. `vingt et un 21`; the word is everything
+    // before the last whitespace. Blank lines and lines starting with `#` are ignored.
+    pub fn parse(contents: &str) -> Result<Vocabulary, String> {
+        let words = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(|(i, line)| {
+                let (word, value) = line
+                    .trim()
+                    .rsplit_once(char::is_whitespace)
+                    .ok_or_else(|| format!("line {}: expected a word and a value", i + 1))?;
+                let value = value
+                    .parse()
+                    .map_err(|_| format!("line {}: invalid value {value:?}", i + 1))?;
+                Ok((word.trim_end().to_string(), value))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Vocabulary::new(words)
+    }
+
+    // A locale name from `LOCALES`, or else the path of a vocabulary file.
+    pub fn load(name: &str) -> Result<Vocabulary, Box<dyn Error>> {
+        if let Some(vocabulary) = Vocabulary::builtin(name) {
+            return Ok(vocabulary);
+        }
+        let contents = fs::read_to_string(name).map_err(|err| {
+            format!(
+                "{name} is not a locale ({}, {COMPOUND}) nor a readable file: {err}",
+                LOCALES.join(", ")
+            )
+        })?;
+        Ok(Vocabulary::parse(&contents).map_err(|err| format!("{name}: {err}"))?)
+    }
+
+    pub fn with_digits(self, digits: Digits) -> Vocabulary {
+        Vocabulary { digits, ..self }
+    }
+
+    pub fn digits(&self) -> Digits {
+        self.digits
+    }
+
+    pub fn words(&self) -> &[(String, u32)] {
+        &self.words
+    }
+
+    // Digits and words, all matched as patterns of the same automaton.
+    pub fn automaton(&self) -> Automaton {
+        let digits = self.digits.chars().into_iter();
+        let digits = digits.map(|(c, d)| (c.to_string(), d));
+        let words = self.words.iter().cloned();
+        Automaton::new(&digits.chain(words).collect::<Vec<_>>())
+    }
+
+    fn from_digits(words: &[&str; 9]) -> Vocabulary {
+        Vocabulary {
+            words: (1..)
+                .zip(words)
+                .map(|(d, word)| (word.to_string(), d))
+                .collect(),
+            digits: Digits::Ascii,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_vocabulary_file() {
+        let vocabulary =
+            Vocabulary::parse("# Dutch\neen 1\n\ntwee\t2\n  drie   3\nvingt et un 1\n").unwrap();
+        assert_eq!(
+            vocabulary.words(),
+            [
+                ("een".to_string(), 1),
+                ("twee".to_string(), 2),
+                ("drie".to_string(), 3),
+                ("vingt et un".to_string(), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn reject_invalid_vocabulary_files() {
+        assert_eq!(
+            Vocabulary::parse("een 1\ntwee\n"),
+            Err("line 2: expected a word and a value".to_string())
+        );
+        assert_eq!(
+            Vocabulary::parse("een one"),
+            Err("line 1: invalid value \"one\"".to_string())
+        );
+        assert_eq!(
+            Vocabulary::parse("  \t 1"),
+            Err("line 1: expected a word and a value".to_string())
+        );
+    }
+
+    #[test]
+    fn builtin_locales() {
+        for locale in LOCALES {
+            let vocabulary = Vocabulary::builtin(locale).unwrap();
+            let values = vocabulary
+                .words()
+                .iter()
+                .map(|(_, v)| *v)
+                .collect::<Vec<_>>();
+            assert_eq!(values, (1..=9).collect::<Vec<_>>());
+        }
+        assert_eq!(Vocabulary::builtin("en"), Some(Vocabulary::english()));
+        assert_eq!(Vocabulary::builtin("nl"), None);
+    }
+
+    #[test]
+    fn compound_numbers() {
+        let compound = Vocabulary::builtin(COMPOUND).unwrap();
+        let value = |word: &str| {
+            compound
+                .words()
+                .iter()
+                .find(|(w, _)| w == word)
+                .map(|(_, value)| *value)
+        };
+        assert_eq!(value("seven"), Some(7));
+        assert_eq!(value("eleven"), Some(11));
+        assert_eq!(value("twenty"), Some(20));
+        assert_eq!(value("forty-two"), Some(42));
+        assert_eq!(value("ninety nine"), Some(99));
+        assert_eq!(value("thirtyone"), Some(31));
+        assert_eq!(value("one hundred"), Some(100));
+        assert_eq!(value("ninehundred"), Some(900));
+        assert_eq!(value("hundred"), None);
+        assert_eq!(compound.words().len(), 9 + 10 + 8 * (1 + 9 * 3) + 9 * 2);
+    }
+}
@@ -1,56 +1,18 @@
-use std::env;
-use std::error::Error;
-use std::fs;
-use std::process;
+use common::grid;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-struct Point {
-    x: usize,
-    y: usize,
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
 }
 
-fn main() {
-    let digits = read_input(env::args()).unwrap_or_else(|err| {
-        println!("Problem parsing arguments: {err}");
-        process::exit(1);
-    });
-
-    let total = run(&digits).unwrap_or_else(|err| {
-        println!("Calculation error: {err}");
-        process::exit(1);
-    });
-
-    println!("total = {:#?}", total);
-}
-
-fn read_input(mut args: impl Iterator<Item = String>) -> Result<String, &'static str> {
-    args.next();
-
-    let file_path = match args.next() {
-        Some(arg) => arg,
-        None => return Err("Didn't get a file path"),
-    };
-
-    Ok(file_path)
-}
-
-fn run(file_path: &str) -> Result<(usize, usize), Box<dyn Error>> {
-    let contents = fs::read_to_string(file_path)?;
-
-    let part_1 = calc_part_1(&contents);
-
-    let part_2 = calc_part_2(&contents);
-
-    Ok((part_1, part_2))
-}
-
-fn calc_part_1(contents: &str) -> usize {
-    let map = parse_input(&contents);
+pub fn calc_part_1(contents: &str) -> usize {
+    let map = parse_input(contents);
     find_boundary(&map).len() / 2
 }
 
-fn calc_part_2(contents: &str) -> usize {
-    let map = parse_input(&contents);
+pub fn calc_part_2(contents: &str) -> usize {
+    let map = parse_input(contents);
     let boundary = find_boundary(&map);
     let tiles = collect_tiles(&map, &boundary);
 
@@ -60,7 +22,61 @@ fn calc_part_2(contents: &str) -> usize {
         .count() as usize
 }
 
-fn is_tile_inside(tile: &Point, boundary: &Vec<Point>) -> bool {
+// Pick's theorem: the loop's area from the shoelace formula is inside + boundary / 2 - 1.
+pub fn calc_part_2_pick(contents: &str) -> usize {
+    let map = parse_input(contents);
+    // The boundary starts and ends on `S`, which closes the polygon for the shoelace formula.
+    let boundary = find_boundary(&map);
+    let twice_area = boundary
+        .windows(2)
+        .map(|w| w[0].x as i64 * w[1].y as i64 - w[1].x as i64 * w[0].y as i64)
+        .sum::<i64>()
+        .unsigned_abs() as usize;
+    let boundary_len = boundary.len() - 1;
+
+    // inside = area - boundary / 2 + 1, doubled so it stays in integers. A loop on the grid
+    // has as many steps one way as back, so its length is even, and Pick's theorem makes
+    // twice the area even too: the single division is exact.
+    (twice_area + 2 - boundary_len) / 2
+}
+
+// Scans each row, flipping inside/outside on every loop tile that connects north.
+pub fn calc_part_2_scanline(contents: &str) -> usize {
+    let map = parse_input(contents);
+    let boundary = find_boundary(&map);
+    let mut on_loop = vec![vec![false; map[0].len()]; map.len()];
+    for point in boundary.iter() {
+        on_loop[point.x][point.y] = true;
+    }
+    let start = &boundary[0];
+    let start_connects_north = [&boundary[1], &boundary[boundary.len() - 2]]
+        .iter()
+        .any(|p| p.x + 1 == start.x);
+
+    let mut count = 0;
+    for (x, row) in map.iter().enumerate() {
+        let mut inside = false;
+        for (y, c) in row.iter().enumerate() {
+            if !on_loop[x][y] {
+                if inside {
+                    count += 1;
+                }
+                continue;
+            }
+            let connects_north = match c {
+                '|' | 'L' | 'J' => true,
+                'S' => start_connects_north,
+                _ => false,
+            };
+            if connects_north {
+                inside = !inside;
+            }
+        }
+    }
+    count
+}
+
+pub fn is_tile_inside(tile: &Point, boundary: &Vec<Point>) -> bool {
     // ray-casting algorithm
     let mut inside = false;
     for i in 0..boundary.len() {
@@ -92,7 +108,7 @@ fn collect_tiles(map: &Vec<Vec<char>>, boundary: &Vec<Point>) -> Vec<Point> {
     tiles
 }
 
-fn find_boundary(map: &Vec<Vec<char>>) -> Vec<Point> {
+pub fn find_boundary(map: &Vec<Vec<char>>) -> Vec<Point> {
     let mut boundary = vec![];
     let mut prev = find_starting_point(&map);
     let mut cur = first_step(&prev, &map);
@@ -226,50 +242,69 @@ fn find_starting_point(map: &Vec<Vec<char>>) -> Point {
     s_coor
 }
 
-fn parse_input(contents: &str) -> Vec<Vec<char>> {
-    contents
-        .lines()
-        .map(|line| line.chars().map(|c| c).collect::<Vec<_>>())
-        .collect::<Vec<_>>()
+pub fn parse_input(contents: &str) -> Vec<Vec<char>> {
+    grid::parse_grid(contents)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // Saved from the puzzle page by `aoc describe`.
+    const EXAMPLE_1: &str = include_str!("../fixtures/example_1.txt");
+    const EXAMPLE_2: &str = include_str!("../fixtures/example_2.txt");
+    const EXAMPLE_3: &str = include_str!("../fixtures/example_3.txt");
+
     #[test]
     fn find_correct_starting_point() {
-        let contents = String::from("..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...");
-        let map = parse_input(&contents);
+        let map = parse_input(EXAMPLE_1);
         assert_eq!(find_starting_point(&map), Point { x: 2, y: 0 });
     }
 
     #[test]
     fn find_first_step() {
-        let contents = String::from("..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...");
-        let map = parse_input(&contents);
+        let map = parse_input(EXAMPLE_1);
         let start = find_starting_point(&map);
         assert_eq!(first_step(&start, &map), Point { x: 2, y: 1 });
     }
 
     #[test]
     fn find_further_point() {
-        let contents = String::from("..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...");
-        assert_eq!(calc_part_1(&contents), 8);
+        assert_eq!(calc_part_1(EXAMPLE_1), 8);
+    }
+
+    #[test]
+    fn boundary_from_start() {
+        let map = parse_input(EXAMPLE_1);
+        insta::assert_debug_snapshot!(find_boundary(&map));
     }
 
     #[test]
     fn test_tile_outside() {
-        let contents = String::from("..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...");
-        let map = parse_input(&contents);
+        let map = parse_input(EXAMPLE_1);
         let boundary = find_boundary(&map);
         assert!(!is_tile_inside(&Point { x: 3, y: 3 }, &boundary));
     }
 
+    #[test]
+    fn inside_tiles_agree_across_implementations() {
+        let examples = [
+            (EXAMPLE_2, 4),
+            (EXAMPLE_3, 8),
+            (EXAMPLE_1, 1),
+            // A loop with nothing inside, where Pick's theorem gives exactly 0.
+            (".....\n.S-7.\n.L-J.\n.....", 0),
+        ];
+        for (contents, inside) in examples {
+            assert_eq!(calc_part_2(contents), inside);
+            assert_eq!(calc_part_2_pick(contents), inside);
+            assert_eq!(calc_part_2_scanline(contents), inside);
+        }
+    }
+
     #[test]
     fn test_tile_inside() {
-        let contents = String::from("...........\n.S-------7.\n.|F-----7|.\n.||.....||.\n.||.....||.\n.|L-7.F-J|.\n.|..|.|..|.\n.L--J.L--J.\n...........");
-        let map = parse_input(&contents);
+        let map = parse_input(EXAMPLE_2);
         let boundary = find_boundary(&map);
         assert!(is_tile_inside(&Point { x: 6, y: 2 }, &boundary));
     }
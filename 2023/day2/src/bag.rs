@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+
+// How many cubes of each color the bag holds. Any color name works, and a color the bag
+// doesn't list has no cubes at all. In a `--query`, a color of more than one word or named like
+// one of the query's keywords or fields (`id`, `power`, `max`, `bag`, `sum`, ...) has to be
+// written in double quotes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    cubes: BTreeMap<String, u32>,
+}
+
+impl Bag {
+    pub fn new(cubes: BTreeMap<String, u32>) -> Result<Bag, String> {
+        if cubes.is_empty() {
+            return Err("the bag has no colors".to_string());
+        }
+        if cubes.keys().any(|color| color.is_empty()) {
+            return Err("empty color".to_string());
+        }
+        Ok(Bag { cubes })
+    }
+
+    // 12 red cubes, 13 green cubes and 14 blue cubes, as in the puzzle.
+    pub fn standard() -> Bag {
+        let cubes = [("red", 12), ("green", 13), ("blue", 14)];
+        Bag {
+            cubes: cubes
+                .into_iter()
+                .map(|(color, count)| (color.to_string(), count))
+                .collect(),
+        }
+    }
+
+    // Counts written like a draw, e.g. `12 red, 13 green, 14 blue`. Colors are separated by
+    // commas or new lines; blank lines and lines starting with `#` are ignored, so the same
+    // syntax works for `--bag` and for a bag file.
+    pub fn parse(contents: &str) -> Result<Bag, String> {
+        let mut cubes = BTreeMap::new();
+        let entries = contents
+            .lines()
+            .filter(|line| !line.trim_start().starts_with('#'))
+            .flat_map(|line| line.split(','))
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty());
+        for entry in entries {
+            let (count, color) = entry
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| format!("expected a count and a color, got {entry:?}"))?;
+            let count = count
+                .parse()
+                .map_err(|_| format!("invalid count {count:?} for {:?}", color.trim()))?;
+            if cubes.insert(color.trim().to_string(), count).is_some() {
+                return Err(format!("{:?} is listed twice", color.trim()));
+            }
+        }
+        Bag::new(cubes)
+    }
+
+    pub fn load(file_path: &str) -> Result<Bag, Box<dyn Error>> {
+        let contents =
+            fs::read_to_string(file_path).map_err(|err| format!("{file_path}: {err}"))?;
+        Ok(Bag::parse(&contents).map_err(|err| format!("{file_path}: {err}"))?)
+    }
+
+    pub fn count(&self, color: &str) -> u32 {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn contains(&self, color: &str) -> bool {
+        self.cubes.contains_key(color)
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = &str> {
+        self.cubes.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_bags() {
+        assert_eq!(Bag::parse("12 red, 13 green, 14 blue"), Ok(Bag::standard()));
+        assert_eq!(
+            Bag::parse("# our variant\n14 blue\n\n12 red,13 green\n"),
+            Ok(Bag::standard())
+        );
+
+        let bag = Bag::parse("3 yellow, 7 dark purple").unwrap();
+        assert_eq!(bag.colors().collect::<Vec<_>>(), ["dark purple", "yellow"]);
+        assert_eq!(bag.count("dark purple"), 7);
+        assert_eq!(bag.count("red"), 0);
+    }
+
+    #[test]
+    fn reject_invalid_bags() {
+        assert_eq!(
+            Bag::parse("12 red, green"),
+            Err("expected a count and a color, got \"green\"".to_string())
+        );
+        assert_eq!(
+            Bag::parse("12 red, x green"),
+            Err("invalid count \"x\" for \"green\"".to_string())
+        );
+        assert_eq!(
+            Bag::parse("12 red, 1 red"),
+            Err("\"red\" is listed twice".to_string())
+        );
+        assert_eq!(
+            Bag::parse("# nothing\n"),
+            Err("the bag has no colors".to_string())
+        );
+    }
+}
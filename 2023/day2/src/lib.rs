@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use bag::Bag;
+use query::{Answer, Query};
+
+pub mod bag;
+pub mod parser;
+pub mod query;
+
+// Cubes by color.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Set {
+    cubes: BTreeMap<String, u32>,
+}
+
+impl Set {
+    pub fn count(&self, color: &str) -> u32 {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+}
+
+impl fmt::Display for Set {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (color, count)) in self.cubes.iter().enumerate() {
+            let separator = if i == 0 { "" } else { ", " };
+            write!(f, "{separator}{count} {color}")?;
+        }
+        Ok(())
+    }
+}
+
+// A game record with all its draws, parsed once by `parser::parse_games` and then queried.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<Set>,
+}
+
+impl Game {
+    // The most cubes of each color shown in one draw, i.e. the fewest the bag could hold.
+    pub fn fewest_cubes(&self) -> Set {
+        let mut set = Set::default();
+        for (color, &number) in self.draws.iter().flat_map(|draw| &draw.cubes) {
+            let max = set.cubes.entry(color.clone()).or_insert(0);
+            *max = number.max(*max);
+        }
+        set
+    }
+}
+
+// Sum of the ids of the games that the bag could have been used for.
+pub const PART_1: &str = "sum id where every color <= bag";
+
+// Sum of the powers of the fewest cubes of each of the bag's colors that make every game
+// possible.
+pub const PART_2: &str = "sum power";
+
+pub fn calc_part_1(contents: &str) -> u64 {
+    let bag = Bag::standard();
+    let games = parser::parse_games(contents, &bag).unwrap_or_else(|err| panic!("{err}"));
+    part_1(&games, &bag)
+}
+
+pub fn calc_part_2(contents: &str) -> u64 {
+    let bag = Bag::standard();
+    let games = parser::parse_games(contents, &bag).unwrap_or_else(|err| panic!("{err}"));
+    part_2(&games, &bag)
+}
+
+pub fn part_1(games: &[Game], bag: &Bag) -> u64 {
+    builtin(PART_1, games, bag)
+}
+
+pub fn part_2(games: &[Game], bag: &Bag) -> u64 {
+    builtin(PART_2, games, bag)
+}
+
+fn builtin(query: &str, games: &[Game], bag: &Bag) -> u64 {
+    let query = Query::parse(query, bag).expect("built-in queries name no colors");
+    match query.run(games, bag) {
+        Answer::Number(n) => n,
+        answer => unreachable!("built-in queries are sums, got {answer:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(calc_part_1(EXAMPLE), 8);
+        assert_eq!(calc_part_2(EXAMPLE), 2286);
+    }
+
+    #[test]
+    fn five_colors() {
+        let bag = Bag::parse("12 red, 13 green, 14 blue, 2 yellow, 5 purple").unwrap();
+        let contents = "\
+Game 1: 3 blue, 4 red, 1 yellow; 2 purple, 2 yellow, 1 green
+Game 2: 1 blue, 3 yellow; 1 red, 1 green, 1 purple
+Game 3: 6 purple, 2 red, 2 green, 2 blue, 2 yellow
+Game 4: 1 blue, 1 red, 1 green; 4 yellow
+";
+        let games = parser::parse_games(contents, &bag).unwrap();
+        assert_eq!(part_1(&games, &bag), 1);
+        // Game 4 has no purple cubes, so its power is 0.
+        assert_eq!(part_2(&games, &bag), 48 + 3 + 96);
+    }
+}
@@ -0,0 +1,130 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::process;
+
+use aoc2023_day2::bag::Bag;
+use aoc2023_day2::parser::parse_games;
+use aoc2023_day2::query::{Answer, Query};
+use aoc2023_day2::{part_1, part_2, Game};
+
+struct Config {
+    file_path: String,
+    bag: Option<BagSource>,
+    query: Option<String>,
+}
+
+enum BagSource {
+    Spec(String),
+    File(String),
+}
+
+fn main() {
+    let config = read_input(env::args()).unwrap_or_else(|err| {
+        println!("Problem parsing arguments: {err}");
+        process::exit(1);
+    });
+
+    if let Some(query) = &config.query {
+        if let Err(err) = run_query(&config, query) {
+            println!("Query error: {err}");
+            process::exit(1);
+        }
+        return;
+    }
+
+    let total = run(&config).unwrap_or_else(|err| {
+        println!("Calculation error: {err}");
+        process::exit(1);
+    });
+
+    println!("total = {:#?}", total);
+}
+
+// aoc2023-day2 <input> [--bag "12 red, 13 green, 14 blue" | --bag-file <path>]
+//     [--query <query>]
+fn read_input(mut args: impl Iterator<Item = String>) -> Result<Config, &'static str> {
+    args.next();
+
+    let mut file_path = None;
+    let mut bag = None;
+    let mut query = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bag" | "--bag-file" if bag.is_some() => {
+                return Err("Only one of --bag and --bag-file can be given")
+            }
+            "--bag" => match args.next() {
+                Some(value) => bag = Some(BagSource::Spec(value)),
+                None => return Err("--bag needs the bag's contents, e.g. \"12 red, 13 green\""),
+            },
+            "--bag-file" => match args.next() {
+                Some(value) => bag = Some(BagSource::File(value)),
+                None => return Err("--bag-file needs a file path"),
+            },
+            "--query" => match args.next() {
+                Some(value) => query = Some(value),
+                None => return Err("--query needs a query, e.g. \"red > 10 and blue < 3\""),
+            },
+            _ if file_path.is_none() => file_path = Some(arg),
+            _ => return Err("Unexpected argument"),
+        }
+    }
+
+    let file_path = match file_path {
+        Some(arg) => arg,
+        None => return Err("Didn't get a file path"),
+    };
+
+    Ok(Config {
+        file_path,
+        bag,
+        query,
+    })
+}
+
+fn run(config: &Config) -> Result<(u64, u64), Box<dyn Error>> {
+    let (bag, games) = load(config)?;
+
+    let part_1 = part_1(&games, &bag);
+
+    let part_2 = part_2(&games, &bag);
+
+    Ok((part_1, part_2))
+}
+
+// Prints the games a query matches with their fewest cubes, the draws it matches, or its
+// aggregate.
+fn run_query(config: &Config, query: &str) -> Result<(), Box<dyn Error>> {
+    let (bag, games) = load(config)?;
+    let query = Query::parse(query, &bag)?;
+
+    match query.run(&games, &bag) {
+        Answer::Games(games) => {
+            for game in games {
+                println!("Game {}: {}", game.id, game.fewest_cubes());
+            }
+        }
+        Answer::Draws(draws) => {
+            for (game, i) in draws {
+                println!("Game {}, draw {}: {}", game.id, i + 1, game.draws[i]);
+            }
+        }
+        Answer::Number(n) => println!("{n}"),
+    }
+
+    Ok(())
+}
+
+fn load(config: &Config) -> Result<(Bag, Vec<Game>), Box<dyn Error>> {
+    let bag = match &config.bag {
+        Some(BagSource::Spec(spec)) => Bag::parse(spec).map_err(|err| format!("--bag: {err}"))?,
+        Some(BagSource::File(file_path)) => Bag::load(file_path)?,
+        None => Bag::standard(),
+    };
+
+    let contents = fs::read_to_string(&config.file_path)?;
+    let games = parse_games(&contents, &bag)?;
+
+    Ok((bag, games))
+}
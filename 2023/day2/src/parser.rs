@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::bag::Bag;
+use crate::{Game, Set};
+
+// The grammar of a game record, where spaces may surround any token and separate the id and
+// each count from what precedes them:
+//
+//     record = "Game" id ":" draw { ";" draw }
+//     draw   = count color { "," count color }
+//
+// A color is everything up to the next `,` or `;` and must be one of the bag's colors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    ExpectedGame,
+    MissingId,
+    InvalidId(String),
+    ExpectedColon,
+    MissingCount,
+    InvalidCount(String),
+    MissingColor,
+    UnknownColor(String),
+    DuplicateColor(String),
+    // The id was already used by the game on the given line.
+    DuplicateId(u32, usize),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::ExpectedGame => write!(f, "expected \"Game\""),
+            ErrorKind::MissingId => write!(f, "missing game id"),
+            ErrorKind::InvalidId(id) => write!(f, "invalid game id {id:?}"),
+            ErrorKind::ExpectedColon => write!(f, "expected \":\" after the game id"),
+            ErrorKind::MissingCount => write!(f, "expected a cube count"),
+            ErrorKind::InvalidCount(count) => write!(f, "invalid cube count {count:?}"),
+            ErrorKind::MissingColor => write!(f, "expected a color after the count"),
+            ErrorKind::UnknownColor(color) => write!(f, "unknown color {color:?}"),
+            ErrorKind::DuplicateColor(color) => write!(f, "{color:?} appears twice in a draw"),
+            ErrorKind::DuplicateId(id, line) => write!(f, "game {id} is already on line {line}"),
+        }
+    }
+}
+
+// The first error in a document, with its 1-based line number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub kind: ErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.kind)
+    }
+}
+
+impl Error for ParseError {}
+
+// Every line is a game record, and no two games share an id.
+pub fn parse_games(contents: &str, bag: &Bag) -> Result<Vec<Game>, ParseError> {
+    let mut lines_by_id = HashMap::new();
+    let mut games = Vec::new();
+    for (i, record) in contents.lines().enumerate() {
+        let error = |kind| ParseError { line: i + 1, kind };
+        let game = parse_game(record, bag).map_err(error)?;
+        if let Some(&line) = lines_by_id.get(&game.id) {
+            return Err(error(ErrorKind::DuplicateId(game.id, line)));
+        }
+        lines_by_id.insert(game.id, i + 1);
+        games.push(game);
+    }
+    Ok(games)
+}
+
+pub fn parse_game(record: &str, bag: &Bag) -> Result<Game, ErrorKind> {
+    let rest = record
+        .trim_start()
+        .strip_prefix("Game")
+        .ok_or(ErrorKind::ExpectedGame)?;
+    let (id, rest) = split_number(rest.trim_start());
+    let id = match id {
+        "" => return Err(ErrorKind::MissingId),
+        id => id
+            .parse()
+            .map_err(|_| ErrorKind::InvalidId(id.to_string()))?,
+    };
+    let draws = rest
+        .trim_start()
+        .strip_prefix(':')
+        .ok_or(ErrorKind::ExpectedColon)?;
+
+    let draws = draws
+        .split(';')
+        .map(|draw| parse_draw(draw, bag))
+        .collect::<Result<_, _>>()?;
+    Ok(Game { id, draws })
+}
+
+fn parse_draw(draw: &str, bag: &Bag) -> Result<Set, ErrorKind> {
+    let mut set = Set::default();
+    for cubes in draw.split(',') {
+        let (count, color) = split_number(cubes.trim_start());
+        let count = match count {
+            "" => return Err(ErrorKind::MissingCount),
+            count => count
+                .parse()
+                .map_err(|_| ErrorKind::InvalidCount(count.to_string()))?,
+        };
+        // The count must be followed by a space, so "3red" isn't read as 3 "red".
+        let color = match color.strip_prefix(char::is_whitespace).map(str::trim) {
+            Some("") | None => return Err(ErrorKind::MissingColor),
+            Some(color) => color,
+        };
+        if !bag.contains(color) {
+            return Err(ErrorKind::UnknownColor(color.to_string()));
+        }
+        if set.cubes.insert(color.to_string(), count).is_some() {
+            return Err(ErrorKind::DuplicateColor(color.to_string()));
+        }
+    }
+    Ok(set)
+}
+
+// The leading ASCII digits of `s`, and the rest.
+fn split_number(s: &str) -> (&str, &str) {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    s.split_at(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(record: &str) -> ErrorKind {
+        parse_game(record, &Bag::standard()).err().unwrap()
+    }
+
+    #[test]
+    fn parse_records() {
+        let bag = Bag::standard();
+        let game = parse_game("Game 7: 3 blue, 4 red; 2 green", &bag).unwrap();
+        assert_eq!(game.id, 7);
+        assert_eq!(game.draws.len(), 2);
+        assert_eq!(game.draws[0].count("red"), 4);
+        assert_eq!(game.draws[0].count("green"), 0);
+        assert_eq!(game.draws[1].count("green"), 2);
+
+        let game = parse_game("  Game   12 :3 blue ;  1 red ,2 green  ", &bag).unwrap();
+        assert_eq!(game.id, 12);
+        assert_eq!(game.draws[1].count("green"), 2);
+    }
+
+    #[test]
+    fn reject_invalid_records() {
+        assert_eq!(error("Gmae 1: 3 blue"), ErrorKind::ExpectedGame);
+        assert_eq!(error("Game: 3 blue"), ErrorKind::MissingId);
+        assert_eq!(error("Game x: 3 blue"), ErrorKind::MissingId);
+        assert_eq!(
+            error("Game 99999999999: 3 blue"),
+            ErrorKind::InvalidId("99999999999".to_string())
+        );
+        assert_eq!(error("Game 1 3 blue"), ErrorKind::ExpectedColon);
+        assert_eq!(error("Game 1: 3 blue;"), ErrorKind::MissingCount);
+        assert_eq!(error("Game 1: blue"), ErrorKind::MissingCount);
+        assert_eq!(error("Game 1: 3blue"), ErrorKind::MissingColor);
+        assert_eq!(error("Game 1: 3 blue, 4 "), ErrorKind::MissingColor);
+        assert_eq!(
+            error("Game 1: 3 blue, 4 orange"),
+            ErrorKind::UnknownColor("orange".to_string())
+        );
+        assert_eq!(
+            error("Game 1: 3 blue; 1 red, 4 blue, 2 red"),
+            ErrorKind::DuplicateColor("red".to_string())
+        );
+    }
+
+    #[test]
+    fn reject_duplicate_ids() {
+        let contents = "Game 1: 1 red\nGame 2: 1 red\nGame 1: 2 red\n";
+        assert_eq!(
+            parse_games(contents, &Bag::standard()).err(),
+            Some(ParseError {
+                line: 3,
+                kind: ErrorKind::DuplicateId(1, 1)
+            })
+        );
+        assert_eq!(
+            parse_games("Game 1: 1 red\nGame 2: 1 red, 2 pink\n", &Bag::standard())
+                .err()
+                .map(|err| err.to_string()),
+            Some("line 2: unknown color \"pink\"".to_string())
+        );
+    }
+}
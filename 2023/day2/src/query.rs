@@ -0,0 +1,595 @@
+use crate::bag::Bag;
+use crate::{Game, Set};
+
+// A small query language over parsed games:
+//
+//     query       = output [ "where" condition ] | condition
+//     output      = "games" | "draws" | "count" | ( "sum" | "max" ) field
+//     field       = "id" | "power" | color
+//     condition   = conjunction { "or" conjunction }
+//     conjunction = unary { "and" unary }
+//     unary       = "not" unary | "(" condition ")"
+//                 | ( "any" | "every" ) "draw" "where" unary
+//                 | subject op value
+//     subject     = field | ( "any" | "every" ) "color"
+//     value       = number | field | "max" | "bag"
+//     op          = "<" | "<=" | ">" | ">=" | "==" | "!="
+//
+// A condition sees a game through its fewest cubes, the most of each color it showed in one
+// draw, except under `any draw` and `every draw`, and for `draws`, where it sees one draw at a
+// time. `max` is the game's most of the color on the left and `bag` the bag's count of it.
+// Colors that aren't a single word, or that are spelled like a keyword or field, are written in
+// double quotes, and a query without an output lists the games, e.g. `red > 10 and blue < 3` or
+// `any draw where green == max`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    output: Output,
+    condition: Option<Condition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Output {
+    Games,
+    Draws,
+    Count,
+    Sum(Field),
+    Max(Field),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Field {
+    Id,
+    Power,
+    Color(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Condition {
+    Or(Box<Condition>, Box<Condition>),
+    And(Box<Condition>, Box<Condition>),
+    Not(Box<Condition>),
+    AnyDraw(Box<Condition>),
+    EveryDraw(Box<Condition>),
+    Compare(Subject, Op, Value),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Subject {
+    Field(Field),
+    AnyColor,
+    EveryColor,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Number(u64),
+    Field(Field),
+    Max,
+    Bag,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+// What a query finds: the matching games, the matching draws as a game and the draw's index,
+// or an aggregate.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Answer<'a> {
+    Games(Vec<&'a Game>),
+    Draws(Vec<(&'a Game, usize)>),
+    Number(u64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Number(u64),
+    Op(Op),
+    Open,
+    Close,
+}
+
+impl Query {
+    // Colors are checked against `bag`, so a misspelled color is an error rather than a
+    // condition that never holds.
+    pub fn parse(text: &str, bag: &Bag) -> Result<Query, String> {
+        let mut parser = Parser {
+            tokens: tokenize(text)?,
+            position: 0,
+            bag,
+        };
+        let query = parser.query()?;
+        match parser.tokens.get(parser.position) {
+            Some(token) => Err(format!("unexpected {}", describe(token))),
+            None => Ok(query),
+        }
+    }
+
+    pub fn run<'a>(&self, games: &'a [Game], bag: &Bag) -> Answer<'a> {
+        let holds = |scope: &Scope| match &self.condition {
+            Some(condition) => condition.holds(scope),
+            None => true,
+        };
+
+        if self.output == Output::Draws {
+            let mut draws = Vec::new();
+            for game in games {
+                let fewest = game.fewest_cubes();
+                for (i, draw) in game.draws.iter().enumerate() {
+                    let scope = Scope {
+                        game,
+                        fewest: &fewest,
+                        set: draw,
+                        bag,
+                    };
+                    if holds(&scope) {
+                        draws.push((game, i));
+                    }
+                }
+            }
+            return Answer::Draws(draws);
+        }
+
+        let mut matches = Vec::new();
+        let mut values = Vec::new();
+        for game in games {
+            let fewest = game.fewest_cubes();
+            let scope = Scope {
+                game,
+                fewest: &fewest,
+                set: &fewest,
+                bag,
+            };
+            if !holds(&scope) {
+                continue;
+            }
+            matches.push(game);
+            if let Output::Sum(field) | Output::Max(field) = &self.output {
+                values.push(scope.field(field));
+            }
+        }
+        match self.output {
+            Output::Games => Answer::Games(matches),
+            Output::Count => Answer::Number(matches.len() as u64),
+            Output::Sum(_) => Answer::Number(values.iter().sum()),
+            Output::Max(_) => Answer::Number(values.into_iter().max().unwrap_or(0)),
+            Output::Draws => unreachable!(),
+        }
+    }
+}
+
+// The product of the counts of every color in the bag.
+pub fn power(set: &Set, bag: &Bag) -> u64 {
+    bag.colors()
+        .map(|color| u64::from(set.count(color)))
+        .product()
+}
+
+// What a condition looks at: `set` is either the game's fewest cubes or one of its draws.
+struct Scope<'a> {
+    game: &'a Game,
+    fewest: &'a Set,
+    set: &'a Set,
+    bag: &'a Bag,
+}
+
+impl Scope<'_> {
+    fn field(&self, field: &Field) -> u64 {
+        match field {
+            Field::Id => u64::from(self.game.id),
+            Field::Power => power(self.set, self.bag),
+            Field::Color(color) => u64::from(self.set.count(color)),
+        }
+    }
+
+    fn in_draw<'a>(&'a self, draw: &'a Set) -> Scope<'a> {
+        Scope { set: draw, ..*self }
+    }
+}
+
+impl Condition {
+    fn holds(&self, scope: &Scope) -> bool {
+        match self {
+            Condition::Or(left, right) => left.holds(scope) || right.holds(scope),
+            Condition::And(left, right) => left.holds(scope) && right.holds(scope),
+            Condition::Not(condition) => !condition.holds(scope),
+            Condition::AnyDraw(condition) => scope
+                .game
+                .draws
+                .iter()
+                .any(|draw| condition.holds(&scope.in_draw(draw))),
+            Condition::EveryDraw(condition) => scope
+                .game
+                .draws
+                .iter()
+                .all(|draw| condition.holds(&scope.in_draw(draw))),
+            Condition::Compare(subject, op, value) => {
+                let compare = |left: u64, color: Option<&str>| {
+                    let right = match (value, color) {
+                        (Value::Number(n), _) => *n,
+                        (Value::Field(field), _) => scope.field(field),
+                        (Value::Max, Some(color)) => u64::from(scope.fewest.count(color)),
+                        (Value::Bag, Some(color)) => u64::from(scope.bag.count(color)),
+                        // The parser only allows `max` and `bag` after a color.
+                        (Value::Max | Value::Bag, None) => unreachable!(),
+                    };
+                    op.apply(left, right)
+                };
+                let color = |color: &str| compare(u64::from(scope.set.count(color)), Some(color));
+                match subject {
+                    Subject::Field(Field::Color(c)) => color(c),
+                    Subject::Field(field) => compare(scope.field(field), None),
+                    Subject::AnyColor => scope.bag.colors().any(color),
+                    Subject::EveryColor => scope.bag.colors().all(color),
+                }
+            }
+        }
+    }
+}
+
+impl Op {
+    const ALL: [Op; 6] = [Op::Le, Op::Ge, Op::Eq, Op::Ne, Op::Lt, Op::Gt];
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Op::Lt => "<",
+            Op::Le => "<=",
+            Op::Gt => ">",
+            Op::Ge => ">=",
+            Op::Eq => "==",
+            Op::Ne => "!=",
+        }
+    }
+
+    fn apply(self, left: u64, right: u64) -> bool {
+        match self {
+            Op::Lt => left < right,
+            Op::Le => left <= right,
+            Op::Gt => left > right,
+            Op::Ge => left >= right,
+            Op::Eq => left == right,
+            Op::Ne => left != right,
+        }
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        let rest = &text[start..];
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let (token, len) = if let Some(quoted) = rest.strip_prefix('"') {
+            let end = quoted.find('"').ok_or("unterminated quote")?;
+            (Token::Quoted(quoted[..end].to_string()), end + 2)
+        } else if c == '(' || c == ')' {
+            (if c == '(' { Token::Open } else { Token::Close }, 1)
+        } else if let Some(op) = Op::ALL.into_iter().find(|op| rest.starts_with(op.symbol())) {
+            // Two-character operators come first so "<=" isn't read as "<".
+            (Token::Op(op), op.symbol().len())
+        } else if c.is_alphanumeric() || c == '_' || c == '-' {
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+                .unwrap_or(rest.len());
+            let word = &rest[..len];
+            let token = match word.parse() {
+                Ok(n) => Token::Number(n),
+                Err(_) if word.starts_with(|c: char| c.is_ascii_digit()) => {
+                    return Err(format!("invalid number {word:?}"))
+                }
+                Err(_) => Token::Word(word.to_string()),
+            };
+            (token, len)
+        } else {
+            return Err(format!("unexpected {c:?}"));
+        };
+        tokens.push(token);
+        while chars.peek().is_some_and(|&(i, _)| i < start + len) {
+            chars.next();
+        }
+    }
+    Ok(tokens)
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Word(word) => format!("{word:?}"),
+        Token::Quoted(color) => format!("\"{color}\""),
+        Token::Number(n) => n.to_string(),
+        Token::Op(op) => format!("{:?}", op.symbol()),
+        Token::Open => "\"(\"".to_string(),
+        Token::Close => "\")\"".to_string(),
+    }
+}
+
+const KEYWORDS: [&str; 14] = [
+    "games", "draws", "count", "sum", "max", "where", "and", "or", "not", "any", "every", "draw",
+    "color", "bag",
+];
+
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    position: usize,
+    bag: &'a Bag,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn eat(&mut self, keyword: &str) -> bool {
+        let found = matches!(self.peek(), Some(Token::Word(word)) if word == keyword);
+        self.position += usize::from(found);
+        found
+    }
+
+    fn expect(&mut self, keyword: &str) -> Result<(), String> {
+        if self.eat(keyword) {
+            return Ok(());
+        }
+        Err(match self.peek() {
+            Some(token) => format!("expected {keyword:?}, got {}", describe(token)),
+            None => format!("expected {keyword:?} at the end"),
+        })
+    }
+
+    fn query(&mut self) -> Result<Query, String> {
+        let output = if self.eat("games") {
+            Output::Games
+        } else if self.eat("draws") {
+            Output::Draws
+        } else if self.eat("count") {
+            Output::Count
+        } else if self.eat("sum") {
+            Output::Sum(self.field()?)
+        } else if self.eat("max") {
+            Output::Max(self.field()?)
+        } else {
+            let condition = self.condition()?;
+            return Ok(Query {
+                output: Output::Games,
+                condition: Some(condition),
+            });
+        };
+        let condition = match self.eat("where") {
+            true => Some(self.condition()?),
+            false => None,
+        };
+        Ok(Query { output, condition })
+    }
+
+    fn condition(&mut self) -> Result<Condition, String> {
+        let mut condition = self.conjunction()?;
+        while self.eat("or") {
+            condition = Condition::Or(Box::new(condition), Box::new(self.conjunction()?));
+        }
+        Ok(condition)
+    }
+
+    fn conjunction(&mut self) -> Result<Condition, String> {
+        let mut condition = self.unary()?;
+        while self.eat("and") {
+            condition = Condition::And(Box::new(condition), Box::new(self.unary()?));
+        }
+        Ok(condition)
+    }
+
+    fn unary(&mut self) -> Result<Condition, String> {
+        if self.eat("not") {
+            return Ok(Condition::Not(Box::new(self.unary()?)));
+        }
+        if self.peek() == Some(&Token::Open) {
+            self.position += 1;
+            let condition = self.condition()?;
+            if self.peek() != Some(&Token::Close) {
+                return Err("expected \")\"".to_string());
+            }
+            self.position += 1;
+            return Ok(condition);
+        }
+
+        let quantifier = match self.peek() {
+            Some(Token::Word(word)) if word == "any" || word == "every" => word.clone(),
+            _ => return self.comparison(),
+        };
+        self.position += 1;
+        let every = quantifier == "every";
+        if self.eat("color") {
+            let subject = if every {
+                Subject::EveryColor
+            } else {
+                Subject::AnyColor
+            };
+            return self.compare(subject);
+        }
+        self.expect("draw")?;
+        self.expect("where")?;
+        let condition = Box::new(self.unary()?);
+        Ok(match every {
+            true => Condition::EveryDraw(condition),
+            false => Condition::AnyDraw(condition),
+        })
+    }
+
+    fn comparison(&mut self) -> Result<Condition, String> {
+        let subject = Subject::Field(self.field()?);
+        self.compare(subject)
+    }
+
+    fn compare(&mut self, subject: Subject) -> Result<Condition, String> {
+        let op = match self.peek() {
+            Some(&Token::Op(op)) => op,
+            Some(token) => return Err(format!("expected a comparison, got {}", describe(token))),
+            None => return Err("expected a comparison at the end".to_string()),
+        };
+        self.position += 1;
+
+        let value = if let Some(&Token::Number(n)) = self.peek() {
+            self.position += 1;
+            Value::Number(n)
+        } else if self.eat("max") {
+            Value::Max
+        } else if self.eat("bag") {
+            Value::Bag
+        } else {
+            Value::Field(self.field()?)
+        };
+        let colorless = matches!(subject, Subject::Field(Field::Id | Field::Power));
+        if matches!(value, Value::Max | Value::Bag) && colorless {
+            return Err("max and bag compare with a color".to_string());
+        }
+        Ok(Condition::Compare(subject, op, value))
+    }
+
+    fn field(&mut self) -> Result<Field, String> {
+        let field = match self.peek() {
+            Some(Token::Word(word)) if word == "id" => Field::Id,
+            Some(Token::Word(word)) if word == "power" => Field::Power,
+            Some(Token::Word(word)) if KEYWORDS.contains(&word.as_str()) => {
+                return Err(format!("expected id, power or a color, got {word:?}"))
+            }
+            Some(Token::Word(color)) | Some(Token::Quoted(color)) => {
+                if !self.bag.contains(color) {
+                    return Err(format!("unknown color {color:?}"));
+                }
+                Field::Color(color.clone())
+            }
+            Some(token) => {
+                return Err(format!(
+                    "expected id, power or a color, got {}",
+                    describe(token)
+                ))
+            }
+            None => return Err("expected id, power or a color at the end".to_string()),
+        };
+        self.position += 1;
+        Ok(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_games;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    // Runs `query` over the example with the puzzle's bag, and hands the answer to `f`.
+    fn run<T>(query: &str, f: impl FnOnce(Answer) -> T) -> T {
+        let bag = Bag::standard();
+        let games = parse_games(EXAMPLE, &bag).unwrap();
+        f(Query::parse(query, &bag).unwrap().run(&games, &bag))
+    }
+
+    fn ids(query: &str) -> Vec<u32> {
+        run(query, |answer| match answer {
+            Answer::Games(games) => games.iter().map(|game| game.id).collect(),
+            answer => panic!("expected games, got {answer:?}"),
+        })
+    }
+
+    fn draws(query: &str) -> Vec<(u32, usize)> {
+        run(query, |answer| match answer {
+            Answer::Draws(draws) => draws.iter().map(|(game, i)| (game.id, *i)).collect(),
+            answer => panic!("expected draws, got {answer:?}"),
+        })
+    }
+
+    fn number(query: &str) -> u64 {
+        run(query, |answer| match answer {
+            Answer::Number(n) => n,
+            answer => panic!("expected a number, got {answer:?}"),
+        })
+    }
+
+    #[test]
+    fn filter_games() {
+        assert_eq!(ids("red > 10 and blue < 15"), [3]);
+        assert_eq!(ids("games where red > 10 and blue < 16"), [3, 4]);
+        assert_eq!(ids("blue > 5 or not (green >= 3)"), [1, 3, 4]);
+        assert_eq!(ids("any color > bag"), [3, 4]);
+        assert_eq!(ids("every color <= bag"), [1, 2, 5]);
+        assert_eq!(ids("id >= 4 and red > blue"), [5]);
+        assert_eq!(ids("games"), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn quantify_draws() {
+        // Games where the most green cubes and the most blue cubes came out together.
+        assert_eq!(
+            ids("any draw where (green == max and blue == max)"),
+            [1, 2, 4]
+        );
+        assert_eq!(ids("every draw where red > 0"), [3, 4, 5]);
+        assert_eq!(ids("not any draw where red > 0"), Vec::<u32>::new());
+        assert_eq!(draws("draws where any color > bag"), [(3, 0), (4, 2)]);
+        assert_eq!(draws("draws where id == 2 and power > 0"), [(2, 1)]);
+    }
+
+    #[test]
+    fn aggregate() {
+        assert_eq!(number("count where red > 5"), 3);
+        assert_eq!(number("sum power"), 2286);
+        assert_eq!(number("sum id where every color <= bag"), 8);
+        assert_eq!(number("max red"), 20);
+        assert_eq!(number("max blue where id < 3"), 6);
+        assert_eq!(number("max power where red > 100"), 0);
+    }
+
+    #[test]
+    fn quoted_colors() {
+        let bag = Bag::parse("2 dark purple, 3 red").unwrap();
+        let games =
+            parse_games("Game 1: 1 dark purple; 3 red\nGame 2: 3 dark purple", &bag).unwrap();
+        let query = Query::parse("\"dark purple\" > bag", &bag).unwrap();
+        assert_eq!(query.run(&games, &bag), Answer::Games(vec![&games[1]]));
+
+        let bag = Bag::parse("2 max, 3 power").unwrap();
+        let games = parse_games("Game 1: 1 max, 3 power\nGame 2: 2 max", &bag).unwrap();
+        let query = Query::parse("\"max\" == max and \"power\" < bag", &bag).unwrap();
+        assert_eq!(query.run(&games, &bag), Answer::Games(vec![&games[1]]));
+        assert_eq!(
+            Query::parse("sum \"power\"", &bag)
+                .unwrap()
+                .run(&games, &bag),
+            Answer::Number(3)
+        );
+    }
+
+    #[test]
+    fn reject_invalid_queries() {
+        let error = |query| Query::parse(query, &Bag::standard()).unwrap_err();
+        assert_eq!(error("red > 10 and purple < 3"), "unknown color \"purple\"");
+        assert_eq!(error("red >"), "expected id, power or a color at the end");
+        assert_eq!(error("red 10"), "expected a comparison, got 10");
+        assert_eq!(error("id == max"), "max and bag compare with a color");
+        assert_eq!(
+            error("any draw green > 1"),
+            "expected \"where\", got \"green\""
+        );
+        assert_eq!(error("(red > 1"), "expected \")\"");
+        assert_eq!(error("sum"), "expected id, power or a color at the end");
+        assert_eq!(error("sum id red"), "unexpected \"red\"");
+        assert_eq!(error("red = 1"), "unexpected '='");
+        assert_eq!(error("red > 1x"), "invalid number \"1x\"");
+        assert_eq!(error("\"red > 1"), "unterminated quote");
+    }
+}
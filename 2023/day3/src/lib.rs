@@ -1,57 +1,20 @@
 use regex::Regex;
 use std::collections::HashMap;
-use std::env;
-use std::error::Error;
-use std::fs;
-use std::process;
-
-#[derive(Debug, Clone)]
-struct Number {
-    value: u32,
-    start_coor: (usize, usize),
-    end_coor: (usize, usize),
-}
-
-#[derive(Debug)]
-struct Symbol {
-    value: String,
-    coor: (usize, usize),
-}
-
-fn main() {
-    let digits = read_input(env::args()).unwrap_or_else(|err| {
-        println!("Problem parsing arguments: {err}");
-        process::exit(1);
-    });
-
-    let total = run(&digits).unwrap_or_else(|err| {
-        println!("Calculation error: {err}");
-        process::exit(1);
-    });
 
-    println!("total = {:#?}", total);
+#[derive(Debug, Clone, PartialEq)]
+pub struct Number {
+    pub value: u32,
+    pub start_coor: (usize, usize),
+    pub end_coor: (usize, usize),
 }
 
-fn read_input(mut args: impl Iterator<Item = String>) -> Result<String, &'static str> {
-    args.next();
-
-    let file_path = match args.next() {
-        Some(arg) => arg,
-        None => return Err("Didn't get a file path"),
-    };
-
-    Ok(file_path)
-}
-
-fn run(file_path: &str) -> Result<(u32, u32), Box<dyn Error>> {
-    let contents = fs::read_to_string(file_path)?;
-
-    let (part_1, part_2) = calc(&contents);
-
-    Ok((part_1, part_2))
+#[derive(Debug)]
+pub struct Symbol {
+    pub value: String,
+    pub coor: (usize, usize),
 }
 
-fn calc(contents: &str) -> (u32, u32) {
+pub fn calc(contents: &str) -> (u32, u32) {
     let (symbols_coors, numbers_coors) = parse_input(contents);
     let mut valid_numbers = vec![];
     let mut gear_ratios = vec![];
@@ -75,7 +38,15 @@ fn scan_neighbors(
     valid_numbers: &mut Vec<Number>,
     gear_ratios: &mut Vec<u32>,
 ) {
-    let mut gear_ratio = vec![];
+    let adjacent = adjacent_numbers(s, numbers_coors);
+    if s.value == "*" && adjacent.len() >= 2 {
+        gear_ratios.push(adjacent[0].value * adjacent[1].value);
+    }
+    valid_numbers.extend(adjacent);
+}
+
+pub fn adjacent_numbers(s: &Symbol, numbers_coors: &HashMap<usize, Vec<Number>>) -> Vec<Number> {
+    let mut adjacent = vec![];
     let min_row = if s.coor.0 > 0 { s.coor.0 - 1 } else { s.coor.0 };
     let max_row = s.coor.0 + 2;
     for search_row in min_row..max_row {
@@ -89,20 +60,15 @@ fn scan_neighbors(
                 };
                 let max_col = number.end_coor.1;
                 if s.coor.1 >= min_col && s.coor.1 <= max_col {
-                    valid_numbers.push(number.clone());
-                    if s.value == "*" {
-                        gear_ratio.push(number.value);
-                        if gear_ratio.len() == 2 {
-                            gear_ratios.push(gear_ratio[0] * gear_ratio[1]);
-                        }
-                    }
+                    adjacent.push(number.clone());
                 }
             }
         }
     }
+    adjacent
 }
 
-fn parse_input(contents: &str) -> (HashMap<usize, Vec<Symbol>>, HashMap<usize, Vec<Number>>) {
+pub fn parse_input(contents: &str) -> (HashMap<usize, Vec<Symbol>>, HashMap<usize, Vec<Number>>) {
     let number_regex = Regex::new(r"\D+").unwrap();
     let numbers = contents
         .lines()
@@ -168,3 +134,23 @@ fn split_with_regex<'a>(
         .split(s)
         .map(move |sub| ((line_coor, addr_of(sub) - addr_of(s)), sub))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    const EXAMPLE: &str = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..";
+
+    #[test]
+    fn example() {
+        assert_eq!(calc(EXAMPLE), (4361, 467835));
+    }
+
+    #[test]
+    fn parse_symbols_and_numbers() {
+        let (symbols, numbers) = parse_input(EXAMPLE);
+        insta::assert_debug_snapshot!("symbols", symbols.into_iter().collect::<BTreeMap<_, _>>());
+        insta::assert_debug_snapshot!("numbers", numbers.into_iter().collect::<BTreeMap<_, _>>());
+    }
+}
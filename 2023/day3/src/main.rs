@@ -0,0 +1,39 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::process;
+
+use aoc2023_day3::calc;
+
+fn main() {
+    let digits = read_input(env::args()).unwrap_or_else(|err| {
+        println!("Problem parsing arguments: {err}");
+        process::exit(1);
+    });
+
+    let total = run(&digits).unwrap_or_else(|err| {
+        println!("Calculation error: {err}");
+        process::exit(1);
+    });
+
+    println!("total = {:#?}", total);
+}
+
+fn read_input(mut args: impl Iterator<Item = String>) -> Result<String, &'static str> {
+    args.next();
+
+    let file_path = match args.next() {
+        Some(arg) => arg,
+        None => return Err("Didn't get a file path"),
+    };
+
+    Ok(file_path)
+}
+
+fn run(file_path: &str) -> Result<(u32, u32), Box<dyn Error>> {
+    let contents = fs::read_to_string(file_path)?;
+
+    let (part_1, part_2) = calc(&contents);
+
+    Ok((part_1, part_2))
+}
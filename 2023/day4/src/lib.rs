@@ -1,46 +1,7 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::env;
-use std::error::Error;
-use std::fs;
-use std::process;
 
-fn main() {
-    let digits = read_input(env::args()).unwrap_or_else(|err| {
-        println!("Problem parsing arguments: {err}");
-        process::exit(1);
-    });
-
-    let total = run(&digits).unwrap_or_else(|err| {
-        println!("Calculation error: {err}");
-        process::exit(1);
-    });
-
-    println!("total = {:#?}", total);
-}
-
-fn read_input(mut args: impl Iterator<Item = String>) -> Result<String, &'static str> {
-    args.next();
-
-    let file_path = match args.next() {
-        Some(arg) => arg,
-        None => return Err("Didn't get a file path"),
-    };
-
-    Ok(file_path)
-}
-
-fn run(file_path: &str) -> Result<(u64, u128), Box<dyn Error>> {
-    let contents = fs::read_to_string(file_path)?;
-
-    let part_1 = calc_winning_points(&contents);
-
-    let part_2 = calc_part_2(&contents);
-
-    Ok((part_1, part_2))
-}
-
-fn calc_winning_points(contents: &str) -> u64 {
+pub fn calc_winning_points(contents: &str) -> u64 {
     contents
         .lines()
         .map(|card| calc_winning_points_for_one_card(card))
@@ -56,7 +17,7 @@ fn calc_winning_points_for_one_card(card: &str) -> u64 {
     }
 }
 
-fn number_of_matching(card: &str) -> usize {
+pub fn number_of_matching(card: &str) -> usize {
     let mut iter = card.split(":").last().unwrap().split("|");
 
     let winnings = iter
@@ -78,7 +39,7 @@ fn number_of_matching(card: &str) -> usize {
     winnings.intersection(&havings).collect::<Vec<_>>().len()
 }
 
-fn calc_part_2(contents: &str) -> u128 {
+pub fn calc_part_2(contents: &str) -> u128 {
     let mut total = HashMap::<usize, u128>::new();
     let max_cards = contents.lines().collect::<Vec<_>>().len();
 
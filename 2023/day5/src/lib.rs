@@ -1,13 +1,9 @@
 use std::collections::HashSet;
-use std::env;
-use std::error::Error;
-use std::fs;
-use std::process;
 
 #[derive(Debug, Clone, Copy)]
-struct Range {
-    begin: u64,
-    end: u64,
+pub struct Range {
+    pub begin: u64,
+    pub end: u64,
 }
 
 struct Line {
@@ -19,42 +15,7 @@ struct Map {
     lines: Vec<Line>,
 }
 
-fn main() {
-    let digits = read_input(env::args()).unwrap_or_else(|err| {
-        println!("Problem parsing arguments: {err}");
-        process::exit(1);
-    });
-
-    let total = run(&digits).unwrap_or_else(|err| {
-        println!("Calculation error: {err}");
-        process::exit(1);
-    });
-
-    println!("total = {:#?}", total);
-}
-
-fn read_input(mut args: impl Iterator<Item = String>) -> Result<String, &'static str> {
-    args.next();
-
-    let file_path = match args.next() {
-        Some(arg) => arg,
-        None => return Err("Didn't get a file path"),
-    };
-
-    Ok(file_path)
-}
-
-fn run(file_path: &str) -> Result<(u64, u64), Box<dyn Error>> {
-    let contents = fs::read_to_string(file_path)?;
-
-    let part_1 = calc_part_1(&contents);
-
-    let part_2 = calc_part_2(&contents);
-
-    Ok((part_1, part_2))
-}
-
-fn calc_part_1(contents: &str) -> u64 {
+pub fn calc_part_1(contents: &str) -> u64 {
     let seeds = parse_input(&contents);
 
     let maps = (1..8)
@@ -84,37 +45,51 @@ fn jump_forward(key: u64, map: &Vec<Line>) -> u64 {
     key
 }
 
-fn calc_part_2(contents: &str) -> u64 {
-    let seeds = parse_seeds_part_2(&contents);
+pub fn calc_part_2(contents: &str) -> u64 {
+    let min_location = calc_location_ranges(contents)
+        .into_iter()
+        .min_by(|first, second| {
+            first.begin.cmp(&second.begin)
+        })
+        .unwrap();
+
+    min_location.begin
+
+}
+
+pub fn calc_location_ranges(contents: &str) -> Vec<Range> {
+    calc_stage_ranges(contents)
+        .into_iter()
+        .flat_map(|stages| stages.into_iter().last().unwrap())
+        .collect::<Vec<_>>()
+}
+
+// For every seed range, the ranges it has been split into before the first map and after each
+// of the seven maps.
+pub fn calc_stage_ranges(contents: &str) -> Vec<Vec<Vec<Range>>> {
+    let seeds = parse_seeds_part_2(contents);
     let maps = (1..8)
         .map(|i| {
-            let mut lines = parse_lines(&contents, i);
-            lines.sort_by(|first, second| first.range.begin.cmp(&second.range.begin));
+            let mut lines = parse_lines(contents, i);
+            lines.sort_by_key(|line| line.range.begin);
             Map { lines }
         })
         .collect::<Vec<_>>();
 
-    let min_location = seeds
+    seeds
         .iter()
         .map(|seed| {
-            let mut ranges = vec![*seed];
+            let mut stages = vec![vec![*seed]];
             for map in maps.iter() {
                 let mut sub_ranges = vec![];
-                for range in ranges.iter() {
+                for range in stages.last().unwrap().iter() {
                     sub_ranges.push(map_range(range, map));
                 }
-                ranges = sub_ranges.into_iter().flatten().collect::<Vec<Range>>();
+                stages.push(sub_ranges.into_iter().flatten().collect::<Vec<Range>>());
             }
-            ranges
+            stages
         })
-        .flatten()
-        .min_by(|first, second| {
-            first.begin.cmp(&second.begin)
-        })
-        .unwrap();
-
-    min_location.begin
-
+        .collect::<Vec<_>>()
 }
 
 fn map_range(range: &Range, map: &Map) -> Vec<Range> {
@@ -215,3 +190,21 @@ fn parse_lines(contents: &str, position: usize) -> Vec<Line> {
         })
         .collect::<Vec<_>>()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "seeds: 79 14 55 13\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\nsoil-to-fertilizer map:\n0 15 37\n37 52 2\n39 0 15\n\nfertilizer-to-water map:\n49 53 8\n0 11 42\n42 0 7\n57 7 4\n\nwater-to-light map:\n88 18 7\n18 25 70\n\nlight-to-temperature map:\n45 77 23\n81 45 19\n68 64 13\n\ntemperature-to-humidity map:\n0 69 1\n1 0 69\n\nhumidity-to-location map:\n60 56 37\n56 93 4";
+
+    #[test]
+    fn example() {
+        assert_eq!(calc_part_1(EXAMPLE), 35);
+        assert_eq!(calc_part_2(EXAMPLE), 46);
+    }
+
+    #[test]
+    fn ranges_after_each_stage() {
+        insta::assert_debug_snapshot!(calc_stage_ranges(EXAMPLE));
+    }
+}
@@ -0,0 +1,133 @@
+use std::ffi::FromVecWithNulError;
+use std::iter::zip;
+
+#[derive(Debug)]
+struct Race {
+    time: u64,
+    distance: u64,
+}
+
+pub fn calc_part_1(contents: &str) -> u64 {
+    calc_part_1_with(contents, calc_loses)
+}
+
+pub fn calc_part_1_closed_form(contents: &str) -> u64 {
+    calc_part_1_with(contents, calc_loses_closed_form)
+}
+
+fn calc_part_1_with(contents: &str, calc_loses: fn(&Race) -> u64) -> u64 {
+    let races = parse_input_part_1(contents);
+    let mut products = vec![];
+
+    for race in races.iter() {
+        products.push(race.time + 1 - 2 * calc_loses(race));
+    }
+
+    products.iter().fold(1, |first, second| first * second)
+}
+
+fn parse_input_part_1(contents: &str) -> Vec<Race> {
+    let mut lines = contents.lines();
+    let time = parse_line_part_1(lines.next().unwrap());
+    let distance = parse_line_part_1(lines.next().unwrap());
+
+    zip(time, distance)
+        .map(|r| Race {
+            time: r.0,
+            distance: r.1,
+        })
+        .collect::<Vec<Race>>()
+}
+
+fn parse_line_part_1(line: &str) -> Vec<u64> {
+    line.split(":")
+        .nth(1)
+        .unwrap()
+        .split(" ")
+        .filter(|t| !t.is_empty())
+        .map(|t| t.parse::<u64>().unwrap())
+        .collect::<Vec<u64>>()
+}
+
+pub fn calc_part_2(contents: &str) -> u64 {
+    calc_part_2_with(contents, calc_loses)
+}
+
+pub fn calc_part_2_closed_form(contents: &str) -> u64 {
+    calc_part_2_with(contents, calc_loses_closed_form)
+}
+
+fn calc_part_2_with(contents: &str, calc_loses: fn(&Race) -> u64) -> u64 {
+    let race = parse_input_part_2(contents);
+
+    race.time + 1 - calc_loses(&race) * 2
+}
+
+fn parse_input_part_2(contents: &str) -> Race {
+    let mut lines = contents.lines();
+    let time = parse_line_part_2(lines.next().unwrap());
+
+    let distance = parse_line_part_2(lines.next().unwrap());
+
+    Race { time, distance }
+}
+
+fn parse_line_part_2(line: &str) -> u64 {
+    line.split(":")
+        .nth(1)
+        .unwrap()
+        .split(" ")
+        .collect::<String>()
+        .parse::<u64>()
+        .unwrap()
+}
+
+fn calc_loses(race: &Race) -> u64 {
+    (0..race.time / 2 + 1)
+        .filter(|velocity| velocity * (race.time - velocity) <= race.distance)
+        .count() as u64
+}
+
+// The distance v * (time - v) grows up to v = time / 2, so the losing velocities in that half are
+// the ones below the smaller root of v * (time - v) = distance.
+fn calc_loses_closed_form(race: &Race) -> u64 {
+    let half = race.time / 2 + 1;
+    let time = race.time as f64;
+    let discriminant = time * time - 4.0 * race.distance as f64;
+    if discriminant < 0.0 {
+        return half;
+    }
+
+    let wins = |velocity: u64| velocity * (race.time - velocity) > race.distance;
+    let mut first_win = ((time - discriminant.sqrt()) / 2.0).floor() as u64;
+    // Nudge past floating point error around the root.
+    while first_win > 0 && wins(first_win - 1) {
+        first_win -= 1;
+    }
+    while first_win < half && !wins(first_win) {
+        first_win += 1;
+    }
+    first_win
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Time:      7  15   30\nDistance:  9  40  200";
+
+    #[test]
+    fn closed_form_matches_brute_force() {
+        assert_eq!(calc_part_1(EXAMPLE), 288);
+        assert_eq!(calc_part_1_closed_form(EXAMPLE), 288);
+        assert_eq!(calc_part_2(EXAMPLE), 71503);
+        assert_eq!(calc_part_2_closed_form(EXAMPLE), 71503);
+
+        for time in 0..40 {
+            for distance in 0..420 {
+                let race = Race { time, distance };
+                assert_eq!(calc_loses_closed_form(&race), calc_loses(&race));
+            }
+        }
+    }
+}
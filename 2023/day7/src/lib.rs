@@ -1,10 +1,6 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::env;
-use std::error::Error;
-use std::fs;
 use std::iter::zip;
-use std::process;
 
 #[derive(Debug)]
 struct Hand {
@@ -23,42 +19,14 @@ enum Kind {
     FiveOfAKind,
 }
 
-fn main() {
-    let digits = read_input(env::args()).unwrap_or_else(|err| {
-        println!("Problem parsing arguments: {err}");
-        process::exit(1);
-    });
+type MatchHand = fn(&Hand) -> Kind;
+type CompareHands = fn(&Hand, &Hand, &HashMap<char, u64>, MatchHand) -> Ordering;
 
-    let total = run(&digits).unwrap_or_else(|err| {
-        println!("Calculation error: {err}");
-        process::exit(1);
-    });
-
-    println!("total = {:#?}", total);
-}
-
-fn read_input(mut args: impl Iterator<Item = String>) -> Result<String, &'static str> {
-    args.next();
-
-    let file_path = match args.next() {
-        Some(arg) => arg,
-        None => return Err("Didn't get a file path"),
-    };
-
-    Ok(file_path)
-}
-
-fn run(file_path: &str) -> Result<(u64, u64), Box<dyn Error>> {
-    let contents = fs::read_to_string(file_path)?;
-
-    let part_1 = calc_part_1(&contents);
-
-    let part_2 = calc_part_2(&contents);
-
-    Ok((part_1, part_2))
+pub fn calc_part_1(contents: &str) -> u64 {
+    calc(&sorted_hands_part_1(contents))
 }
 
-fn calc_part_1(contents: &str) -> u64 {
+fn sorted_hands_part_1(contents: &str) -> Vec<Hand> {
     let cards = HashMap::from([
         ('2', 0),
         ('3', 1),
@@ -74,7 +42,7 @@ fn calc_part_1(contents: &str) -> u64 {
         ('K', 11),
         ('A', 12),
     ]);
-    calc(&contents, compare_hands, match_hand_part_1, &cards)
+    sort_hands(contents, compare_hands, match_hand_part_1, &cards)
 }
 
 fn match_hand_part_1(hand: &Hand) -> Kind {
@@ -101,7 +69,11 @@ fn match_hand_part_1(hand: &Hand) -> Kind {
     }
 }
 
-fn calc_part_2(contents: &str) -> u64 {
+pub fn calc_part_2(contents: &str) -> u64 {
+    calc(&sorted_hands_part_2(contents))
+}
+
+fn sorted_hands_part_2(contents: &str) -> Vec<Hand> {
     let cards = HashMap::from([
         ('J', 0),
         ('2', 1),
@@ -117,23 +89,10 @@ fn calc_part_2(contents: &str) -> u64 {
         ('K', 11),
         ('A', 12),
     ]);
-    calc(&contents, compare_hands,  match_hand_part_2, &cards)
+    sort_hands(contents, compare_hands, match_hand_part_2, &cards)
 }
 
-fn calc(
-    contents: &str,
-    compare_func: fn(
-        first: &Hand,
-        second: &Hand,
-        cards: &HashMap<char, u64>,
-        match_hand_func: fn(hand: &Hand) -> Kind,
-    ) -> Ordering,
-    match_hand_func: fn(hand: &Hand) -> Kind,
-    cards: &HashMap<char, u64>,
-) -> u64 {
-    let mut hands = parse_input(&contents);
-    hands.sort_by(|first, second| compare_func(first, second, cards, match_hand_func));
-
+fn calc(hands: &[Hand]) -> u64 {
     hands
         .iter()
         .enumerate()
@@ -141,6 +100,18 @@ fn calc(
         .sum()
 }
 
+// Weakest hand first, so each hand's rank is its position plus one.
+fn sort_hands(
+    contents: &str,
+    compare_func: CompareHands,
+    match_hand_func: MatchHand,
+    cards: &HashMap<char, u64>,
+) -> Vec<Hand> {
+    let mut hands = parse_input(contents);
+    hands.sort_by(|first, second| compare_func(first, second, cards, match_hand_func));
+    hands
+}
+
 fn compare_hands(
     first: &Hand,
     second: &Hand,
@@ -235,6 +206,32 @@ fn parse_input(contents: &str) -> Vec<Hand> {
 mod tests {
     use super::*;
 
+    const EXAMPLE: &str = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483";
+
+    #[test]
+    fn example() {
+        assert_eq!(calc_part_1(EXAMPLE), 6440);
+        assert_eq!(calc_part_2(EXAMPLE), 5905);
+    }
+
+    #[test]
+    fn sorted_hand_order() {
+        let order = |hands: Vec<Hand>, match_hand_func: MatchHand| {
+            hands
+                .into_iter()
+                .map(|hand| (hand.card.clone(), match_hand_func(&hand), hand.bid))
+                .collect::<Vec<_>>()
+        };
+        insta::assert_debug_snapshot!(
+            "part_1",
+            order(sorted_hands_part_1(EXAMPLE), match_hand_part_1)
+        );
+        insta::assert_debug_snapshot!(
+            "part_2",
+            order(sorted_hands_part_2(EXAMPLE), match_hand_part_2)
+        );
+    }
+
     #[test]
     fn five_of_a_kind() {
         let hand = Hand {
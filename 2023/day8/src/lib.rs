@@ -1,51 +1,13 @@
+use common::math::lcm;
 use std::collections::HashMap;
-use std::env;
-use std::error::Error;
-use std::fs;
-use std::process;
 
 #[derive(Debug)]
-struct Net {
-    left: String,
-    right: String,
+pub struct Net {
+    pub left: String,
+    pub right: String,
 }
 
-fn main() {
-    let digits = read_input(env::args()).unwrap_or_else(|err| {
-        println!("Problem parsing arguments: {err}");
-        process::exit(1);
-    });
-
-    let total = run(&digits).unwrap_or_else(|err| {
-        println!("Calculation error: {err}");
-        process::exit(1);
-    });
-
-    println!("total = {:#?}", total);
-}
-
-fn read_input(mut args: impl Iterator<Item = String>) -> Result<String, &'static str> {
-    args.next();
-
-    let file_path = match args.next() {
-        Some(arg) => arg,
-        None => return Err("Didn't get a file path"),
-    };
-
-    Ok(file_path)
-}
-
-fn run(file_path: &str) -> Result<(u64, u64), Box<dyn Error>> {
-    let contents = fs::read_to_string(file_path)?;
-
-    let part_1 = calc_part_1(&contents);
-
-    let part_2 = calc_part_2(&contents);
-
-    Ok((part_1, part_2))
-}
-
-fn calc_part_1(contents: &str) -> u64 {
+pub fn calc_part_1(contents: &str) -> u64 {
     let (_, instructions, map) = parse_input(&contents);
     let start_node = String::from("AAA");
 
@@ -54,7 +16,7 @@ fn calc_part_1(contents: &str) -> u64 {
     steps
 }
 
-fn calc_part_2(contents: &str) -> u64 {
+pub fn calc_part_2(contents: &str) -> u64 {
     let (start_nodes, instructions, map) = parse_input(&contents);
 
     // It's seriously a damn loop after the first round! If it's different, then it's hell!
@@ -72,24 +34,6 @@ fn calc_part_2(contents: &str) -> u64 {
     steps
 }
 
-fn lcm(a: u64, b: u64) -> u64 {
-    a * b / gcd(a, b)
-}
-
-fn gcd(a: u64, b: u64) -> u64 {
-    let mut x = a.max(b);
-    let mut y = a.min(b);
-    loop {
-        let r = x % y;
-        if r == 0 {
-            break;
-        }
-        x = y;
-        y = r;
-    }
-    y
-}
-
 fn navigate(
     first_node: String,
     instructions: &Vec<char>,
@@ -112,7 +56,7 @@ fn navigate(
     steps
 }
 
-fn jump_one_step<'a>(
+pub fn jump_one_step<'a>(
     current_node: &'a str,
     instruction: char,
     map: &'a HashMap<String, Net>,
@@ -123,7 +67,7 @@ fn jump_one_step<'a>(
     }
 }
 
-fn parse_input(contents: &str) -> (Vec<String>, Vec<char>, HashMap<String, Net>) {
+pub fn parse_input(contents: &str) -> (Vec<String>, Vec<char>, HashMap<String, Net>) {
     let mut iter = contents.split("\n\n");
 
     let instructions = iter.next().unwrap().chars().collect::<Vec<_>>();
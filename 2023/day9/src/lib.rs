@@ -0,0 +1,3 @@
+pub mod part_1;
+pub mod part_2;
+pub mod utils;
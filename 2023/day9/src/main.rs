@@ -3,9 +3,7 @@ use std::error::Error;
 use std::fs;
 use std::process;
 
-pub mod part_1;
-pub mod part_2;
-pub mod utils;
+use aoc2023_day9::{part_1, part_2};
 
 fn main() {
     let digits = read_input(env::args()).unwrap_or_else(|err| {
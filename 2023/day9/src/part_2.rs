@@ -0,0 +1,21 @@
+use crate::utils;
+
+pub fn calc_part_2(contents: &str) -> i64 {
+    let numbers = utils::parse_input(&contents);
+
+    numbers.iter().map(|line| previous_value(line)).sum()
+}
+
+pub fn previous_value(line: &[i64]) -> i64 {
+    let mut curr_numbers = line.to_vec();
+    let mut first_numbers = vec![];
+    while !curr_numbers.iter().all(|v| *v == 0) {
+        first_numbers.push(curr_numbers[0]);
+        curr_numbers = curr_numbers
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .collect::<Vec<_>>();
+    }
+
+    first_numbers.iter().rev().fold(0, |acc, x| x - acc)
+}
@@ -0,0 +1,103 @@
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Gives every solution a version made of its crate version and a hash of its sources and
+// manifest, plus what every day shares: the `common` crate and the runner's solution registry
+// that picks each day's implementations. Only tracked files go into the hash, so two checkouts
+// of the same commit agree on it. Cached answers go stale only when one of those changes.
+fn main() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let root = manifest_dir.parent().unwrap();
+    let shared_dirs = [root.join("common/src"), manifest_dir.join("src/solutions")];
+    let common_manifest = root.join("common/Cargo.toml");
+    let mut shared = vec![];
+    for dir in &shared_dirs {
+        println!("cargo:rerun-if-changed={}", dir.display());
+        shared.extend(source_files(dir));
+    }
+    println!("cargo:rerun-if-changed={}", common_manifest.display());
+    shared.push(common_manifest);
+
+    let mut versions = vec![];
+    for year in sorted_dirs(root).filter(|dir| is_number(dir, "")) {
+        for day in sorted_dirs(&year).filter(|dir| is_number(dir, "day")) {
+            println!("cargo:rerun-if-changed={}", day.join("src").display());
+            println!(
+                "cargo:rerun-if-changed={}",
+                day.join("Cargo.toml").display()
+            );
+
+            let mut hasher = Sha256::new();
+            let mut files = source_files(&day.join("src"));
+            files.push(day.join("Cargo.toml"));
+            for file in files.iter().chain(&shared) {
+                hasher.update(fs::read(file).unwrap());
+            }
+            let hash = format!("{:x}", hasher.finalize());
+            versions.push(format!(
+                "    ({}, {}, \"{}+{}\"),\n",
+                file_name(&year),
+                file_name(&day).trim_start_matches("day"),
+                package_version(&day.join("Cargo.toml")),
+                hash
+            ));
+        }
+    }
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("solver_versions.rs");
+    fs::write(
+        out,
+        format!(
+            "pub const SOLVER_VERSIONS: &[(u16, u8, &str)] = &[\n{}];\n",
+            versions.concat()
+        ),
+    )
+    .unwrap();
+}
+
+fn sorted_dirs(dir: &Path) -> impl Iterator<Item = PathBuf> {
+    let mut dirs = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+    dirs.sort();
+    dirs.into_iter()
+}
+
+fn source_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = vec![];
+    for path in sorted_dirs(dir) {
+        files.extend(source_files(&path));
+    }
+    let mut here = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect::<Vec<_>>();
+    here.sort();
+    files.extend(here);
+    files
+}
+
+fn file_name(path: &Path) -> &str {
+    path.file_name().unwrap().to_str().unwrap()
+}
+
+fn is_number(path: &Path, prefix: &str) -> bool {
+    file_name(path)
+        .strip_prefix(prefix)
+        .is_some_and(|rest| !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn package_version(manifest: &Path) -> String {
+    fs::read_to_string(manifest)
+        .unwrap()
+        .lines()
+        .find_map(|line| line.strip_prefix("version = "))
+        .unwrap()
+        .trim_matches('"')
+        .to_owned()
+}
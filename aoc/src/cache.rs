@@ -0,0 +1,81 @@
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::PathBuf;
+
+include!(concat!(env!("OUT_DIR"), "/solver_versions.rs"));
+
+pub fn solver_version(year: u16, day: u8) -> Option<&'static str> {
+    SOLVER_VERSIONS
+        .iter()
+        .find(|(y, d, _)| *y == year && *d == day)
+        .map(|(_, _, version)| *version)
+}
+
+pub fn key(year: u16, day: u8, part: u8, contents: &str) -> String {
+    let input = format!("{:x}", Sha256::digest(contents.as_bytes()));
+    let version = solver_version(year, day).unwrap_or("unknown");
+    format!("{year}-day{day:02}-part{part}-{version}-{input}")
+}
+
+// One file per answer, named by its key.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: PathBuf) -> Cache {
+        Cache { dir }
+    }
+
+    pub fn get(&self, key: &str) -> io::Result<Option<String>> {
+        match fs::read_to_string(self.dir.join(key)) {
+            Ok(answer) => Ok(Some(answer)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn put(&self, key: &str, answer: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.dir.join(key), answer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_solution_has_a_version() {
+        for solution in crate::solutions::all() {
+            let version = solver_version(solution.year, solution.day).unwrap();
+            assert!(version.starts_with("0.1.0+"));
+            assert_eq!(version.len(), "0.1.0+".len() + 64);
+        }
+        assert_ne!(solver_version(2023, 1), solver_version(2023, 2));
+    }
+
+    #[test]
+    fn key_depends_on_every_part() {
+        let key_1 = key(2023, 5, 1, "seeds: 1 2");
+        assert!(key_1.starts_with("2023-day05-part1-0.1.0+"));
+        assert!(key_1.ends_with(&format!("{:x}", Sha256::digest(b"seeds: 1 2"))));
+        assert_eq!(key_1, key(2023, 5, 1, "seeds: 1 2"));
+        assert_ne!(key_1, key(2023, 5, 2, "seeds: 1 2"));
+        assert_ne!(key_1, key(2023, 6, 1, "seeds: 1 2"));
+        assert_ne!(key_1, key(2023, 5, 1, "seeds: 1 3"));
+    }
+
+    #[test]
+    fn store_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path().join("answers"));
+        assert_eq!(cache.get("2023-day05-part1").unwrap(), None);
+        cache.put("2023-day05-part1", "35").unwrap();
+        assert_eq!(
+            cache.get("2023-day05-part1").unwrap(),
+            Some(String::from("35"))
+        );
+    }
+}
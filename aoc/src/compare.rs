@@ -0,0 +1,107 @@
+use std::hint;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use crate::solutions::Solver;
+
+#[derive(Debug, PartialEq)]
+pub struct Outcome {
+    pub name: &'static str,
+    pub answer: Result<String, String>,
+}
+
+// Runs every implementation on the same input; a panic is reported as that implementation's
+// outcome instead of stopping the others.
+pub fn run_all(implementations: &[(&'static str, Solver)], contents: &str) -> Vec<Outcome> {
+    implementations
+        .iter()
+        .map(|(name, solver)| Outcome {
+            name,
+            answer: panic::catch_unwind(AssertUnwindSafe(|| solver(contents)))
+                .map_err(|payload| panic_message(payload.as_ref())),
+        })
+        .collect()
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default();
+    format!("panicked: {message}")
+}
+
+pub fn agree(outcomes: &[Outcome]) -> bool {
+    outcomes
+        .iter()
+        .all(|o| o.answer.is_ok() && o.answer == outcomes[0].answer)
+}
+
+#[derive(Debug)]
+pub struct Timing {
+    pub name: &'static str,
+    pub min: Duration,
+    pub mean: Duration,
+}
+
+pub fn bench(implementations: &[(&'static str, Solver)], contents: &str, runs: u32) -> Vec<Timing> {
+    implementations
+        .iter()
+        .map(|(name, solver)| {
+            let times = (0..runs.max(1))
+                .map(|_| {
+                    let start = Instant::now();
+                    hint::black_box(solver(hint::black_box(contents)));
+                    start.elapsed()
+                })
+                .collect::<Vec<_>>();
+            Timing {
+                name,
+                min: *times.iter().min().unwrap(),
+                mean: times.iter().sum::<Duration>() / times.len() as u32,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn implementations() -> Vec<(&'static str, Solver)> {
+        vec![
+            ("default", |c| c.len().to_string()),
+            ("chars", |c| c.chars().count().to_string()),
+        ]
+    }
+
+    #[test]
+    fn agreeing_implementations() {
+        let outcomes = run_all(&implementations(), "0 3 6");
+        assert_eq!(outcomes[1].name, "chars");
+        assert_eq!(outcomes[1].answer, Ok(String::from("5")));
+        assert!(agree(&outcomes));
+    }
+
+    #[test]
+    fn report_disagreement_and_panics() {
+        let mut implementations = implementations();
+        implementations.push(("words", |c| c.split_whitespace().count().to_string()));
+        assert!(!agree(&run_all(&implementations, "0 3 6")));
+
+        implementations.pop();
+        implementations.push(("broken", |_| panic!("no input")));
+        let outcomes = run_all(&implementations, "0 3 6");
+        assert_eq!(outcomes[2].answer, Err(String::from("panicked: no input")));
+        assert!(!agree(&outcomes));
+    }
+
+    #[test]
+    fn time_every_implementation() {
+        let timings = bench(&implementations(), "0 3 6", 3);
+        assert_eq!(timings.len(), 2);
+        assert_eq!(timings[0].name, "default");
+        assert!(timings[0].min <= timings[0].mean);
+    }
+}
@@ -0,0 +1,65 @@
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+// Inputs and known answers live in per-year directories under a data root:
+//   <root>/inputs/<year>/day05.txt
+//   <root>/answers/<year>/day05.txt    (part 1 answer on the first line, part 2 on the second)
+// answers computed by the runner are cached under <root>/.aoc-cache, and solution plugins are
+// loaded from <root>/plugins.
+pub fn input_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join("inputs")
+        .join(year.to_string())
+        .join(format!("day{day:02}.txt"))
+}
+
+pub fn answer_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join("answers")
+        .join(year.to_string())
+        .join(format!("day{day:02}.txt"))
+}
+
+pub fn cache_dir(root: &Path) -> PathBuf {
+    root.join(".aoc-cache")
+}
+
+pub fn plugin_dir(root: &Path) -> PathBuf {
+    root.join("plugins")
+}
+
+pub fn read_answers(path: &Path) -> io::Result<[Option<String>; 2]> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok([None, None]),
+        Err(err) => return Err(err),
+    };
+
+    let mut lines = contents
+        .lines()
+        .map(|line| Some(line.trim().to_owned()).filter(|line| !line.is_empty()));
+    Ok([lines.next().flatten(), lines.next().flatten()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_per_year() {
+        let root = Path::new("data");
+        assert_eq!(
+            input_path(root, 2023, 5),
+            Path::new("data/inputs/2023/day05.txt")
+        );
+        assert_eq!(
+            answer_path(root, 2022, 12),
+            Path::new("data/answers/2022/day12.txt")
+        );
+    }
+
+    #[test]
+    fn missing_answers() {
+        let answers = read_answers(Path::new("does/not/exist.txt")).unwrap();
+        assert_eq!(answers, [None, None]);
+    }
+}
@@ -0,0 +1,242 @@
+use scraper::{ElementRef, Html, Node, Selector};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::http::{HttpClient, BASE_URL};
+
+#[derive(Debug, PartialEq)]
+pub struct Description {
+    pub markdown: String,
+    pub examples: Vec<String>,
+}
+
+pub fn fetch(client: &dyn HttpClient, year: u16, day: u8) -> Result<Description, Box<dyn Error>> {
+    let html = client.get(&format!("{BASE_URL}/{year}/day/{day}"))?;
+    let description = parse(&html);
+    if description.markdown.is_empty() {
+        return Err(format!("no puzzle description found for {year} day {day}").into());
+    }
+    Ok(description)
+}
+
+// Converts every `<article class="day-desc">` (part 2 only shows up once part 1 is solved). A
+// `<pre><code>` block is kept as an example input when the paragraph before it introduces an
+// example ("For example:") and an answer (`<code><em>`) comes after it, which leaves out
+// diagrams like the distances drawn over a map.
+pub fn parse(html: &str) -> Description {
+    let document = Html::parse_document(html);
+    let articles = Selector::parse("article.day-desc").unwrap();
+    let elements = Selector::parse("p, pre > code, code > em").unwrap();
+
+    let mut sections = vec![];
+    let mut examples = vec![];
+    for article in document.select(&articles) {
+        let mut markdown = String::new();
+        write_block_children(article, &mut markdown);
+        sections.push(markdown.trim().to_owned());
+
+        let mut introduced = false;
+        let mut unanswered = vec![];
+        for element in article.select(&elements) {
+            match element.value().name() {
+                "p" => {
+                    let text = element.text().collect::<String>();
+                    introduced = text.to_lowercase().contains("example");
+                }
+                "code" if introduced => unanswered.push(element.text().collect::<String>()),
+                "code" => {}
+                // Highlighted tiles inside a block aren't answers.
+                _ if !is_in_block(element) => examples.append(&mut unanswered),
+                _ => {}
+            }
+        }
+    }
+
+    Description {
+        markdown: sections.join("\n\n"),
+        examples,
+    }
+}
+
+fn is_in_block(em: ElementRef) -> bool {
+    em.parent()
+        .and_then(|code| code.parent())
+        .and_then(ElementRef::wrap)
+        .is_some_and(|pre| pre.value().name() == "pre")
+}
+
+pub fn fixture_dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string())
+        .join(format!("day{day}"))
+        .join("fixtures")
+}
+
+// Examples saved earlier may have been trimmed or fixed by hand, so they are only replaced
+// when `force` is set.
+pub fn save_examples(
+    examples: &[String],
+    dir: &Path,
+    force: bool,
+) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let paths = (1..=examples.len())
+        .map(|k| dir.join(format!("example_{k}.txt")))
+        .collect::<Vec<_>>();
+    if let Some(path) = paths.iter().find(|path| !force && path.exists()) {
+        return Err(format!(
+            "{} already exists; pass --force to replace it",
+            path.display()
+        )
+        .into());
+    }
+
+    fs::create_dir_all(dir)?;
+    for (path, example) in paths.iter().zip(examples) {
+        fs::write(path, example)?;
+    }
+    Ok(paths)
+}
+
+fn write_block_children(element: ElementRef, out: &mut String) {
+    for child in element.children() {
+        let Some(child) = ElementRef::wrap(child) else {
+            continue;
+        };
+        match child.value().name() {
+            "h2" => {
+                out.push_str("## ");
+                out.push_str(inline(child).trim().trim_matches('-').trim());
+                out.push_str("\n\n");
+            }
+            "p" => {
+                out.push_str(inline(child).trim());
+                out.push_str("\n\n");
+            }
+            "pre" => {
+                let code = child.text().collect::<String>();
+                out.push_str("```\n");
+                out.push_str(&code);
+                if !code.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            "ul" | "ol" => {
+                for item in child.child_elements().filter(|e| e.value().name() == "li") {
+                    out.push_str("- ");
+                    out.push_str(inline(item).trim());
+                    out.push('\n');
+                }
+                out.push('\n');
+            }
+            _ => write_block_children(child, out),
+        }
+    }
+}
+
+fn inline(element: ElementRef) -> String {
+    let mut out = String::new();
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => out.push_str(&text.replace('\n', " ")),
+            Node::Element(_) => {
+                let child = ElementRef::wrap(child).unwrap();
+                let text = inline(child);
+                match child.value().name() {
+                    // `<code><em>8</em></code>` is how answers are highlighted.
+                    "code" if child.child_elements().next().is_some() => out.push_str(&text),
+                    "code" => out.push_str(&format!("`{text}`")),
+                    "em" => out.push_str(&format!("**{text}**")),
+                    "a" => match child.attr("href") {
+                        Some(href) => out.push_str(&format!("[{text}]({})", absolute(href))),
+                        None => out.push_str(&text),
+                    },
+                    _ => out.push_str(&text),
+                }
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+fn absolute(href: &str) -> String {
+    if href.starts_with('/') {
+        format!("{BASE_URL}{href}")
+    } else {
+        href.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::mock::MockClient;
+    use std::collections::HashMap;
+
+    const PAGE: &str = include_str!("../tests/fixtures/describe_day10.html");
+
+    #[test]
+    fn convert_articles_to_markdown() {
+        let description = parse(PAGE);
+        let markdown = description.markdown;
+
+        assert!(markdown
+            .starts_with("## Day 10: Pipe Maze\n\nThe pipes form a **single continuous loop**."));
+        assert!(markdown
+            .contains("Here is a `7` bend, see [the map](https://adventofcode.com/2023/map)."));
+        assert!(markdown.contains("- `|` is a vertical pipe.\n- `-` is a horizontal pipe.\n"));
+        assert!(markdown.contains("```\n.....\n.S-7.\n.|.|.\n.L-J.\n.....\n```"));
+        assert!(markdown.contains("How many steps does it take? **8**"));
+        assert!(markdown.contains("## Part Two\n\nCount the tiles **enclosed by the loop**."));
+        assert!(!markdown.contains("<"));
+    }
+
+    #[test]
+    fn extract_answered_examples_from_both_parts() {
+        let description = parse(PAGE);
+        assert_eq!(description.examples.len(), 2);
+        assert_eq!(
+            description.examples[0],
+            ".....\n.S-7.\n.|.|.\n.L-J.\n.....\n"
+        );
+        assert!(description.examples[1].starts_with("...........\n.S-------7.\n"));
+    }
+
+    #[test]
+    fn fetch_and_save_examples() {
+        let client = MockClient {
+            pages: HashMap::from([(
+                String::from("https://adventofcode.com/2023/day/10"),
+                String::from(PAGE),
+            )]),
+        };
+        let description = fetch(&client, 2023, 10).unwrap();
+        assert!(fetch(&client, 2023, 11).is_err());
+
+        let root = tempfile::tempdir().unwrap();
+        let dir = fixture_dir(root.path(), 2023, 10);
+        assert!(dir.ends_with("2023/day10/fixtures"));
+        let paths = save_examples(&description.examples, &dir, false).unwrap();
+        assert_eq!(paths.len(), 2);
+        assert_eq!(
+            fs::read_to_string(dir.join("example_1.txt")).unwrap(),
+            description.examples[0]
+        );
+
+        fs::write(dir.join("example_1.txt"), ".S7\n.LJ\n").unwrap();
+        let err = save_examples(&description.examples, &dir, false).unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("example_1.txt already exists; pass --force to replace it"));
+        assert_eq!(
+            fs::read_to_string(dir.join("example_1.txt")).unwrap(),
+            ".S7\n.LJ\n"
+        );
+        save_examples(&description.examples, &dir, true).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join("example_1.txt")).unwrap(),
+            description.examples[0]
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use std::env;
+use std::error::Error;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+pub trait HttpClient {
+    fn get(&self, url: &str) -> Result<String, Box<dyn Error>>;
+}
+
+// Talks to adventofcode.com with the session cookie from `AOC_SESSION`, which private
+// leaderboards and personal puzzle pages need.
+pub struct AocClient {
+    session: Option<String>,
+}
+
+impl AocClient {
+    pub fn from_env() -> AocClient {
+        AocClient {
+            session: env::var("AOC_SESSION").ok(),
+        }
+    }
+}
+
+impl HttpClient for AocClient {
+    fn get(&self, url: &str) -> Result<String, Box<dyn Error>> {
+        let mut request = ureq::get(url).header("User-Agent", "github.com/da0p/AoC_2023");
+        if let Some(session) = &self.session {
+            request = request.header("Cookie", &format!("session={session}"));
+        }
+        let body = request.call()?.body_mut().read_to_string()?;
+        Ok(body)
+    }
+}
+
+#[cfg(test)]
+pub mod mock {
+    use super::*;
+    use std::collections::HashMap;
+
+    pub struct MockClient {
+        pub pages: HashMap<String, String>,
+    }
+
+    impl HttpClient for MockClient {
+        fn get(&self, url: &str) -> Result<String, Box<dyn Error>> {
+            self.pages
+                .get(url)
+                .cloned()
+                .ok_or_else(|| format!("404 for {url}").into())
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Write;
+
+use crate::http::{HttpClient, BASE_URL};
+
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    #[serde(default)]
+    pub local_score: u64,
+    #[serde(default)]
+    pub completion_day_level: HashMap<String, HashMap<String, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+    #[serde(default)]
+    pub star_index: u64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DayTimes {
+    pub part_1: Option<i64>,
+    pub part_2: Option<i64>,
+}
+
+impl DayTimes {
+    pub fn delta(&self) -> Option<i64> {
+        Some(self.part_2? - self.part_1?)
+    }
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    pub fn star(&self, day: u8, part: u8) -> Option<&Star> {
+        self.completion_day_level
+            .get(&day.to_string())?
+            .get(&part.to_string())
+    }
+
+    // Seconds from the puzzle unlocking to each star.
+    pub fn times(&self, year: i64, day: u8) -> DayTimes {
+        let unlock = unlock_timestamp(year, day);
+        DayTimes {
+            part_1: self.star(day, 1).map(|s| s.get_star_ts - unlock),
+            part_2: self.star(day, 2).map(|s| s.get_star_ts - unlock),
+        }
+    }
+}
+
+pub fn fetch(client: &dyn HttpClient, year: u16, id: &str) -> Result<Leaderboard, Box<dyn Error>> {
+    let url = format!("{BASE_URL}/{year}/leaderboard/private/view/{id}.json");
+    parse(&client.get(&url)?)
+}
+
+pub fn parse(json: &str) -> Result<Leaderboard, Box<dyn Error>> {
+    Ok(serde_json::from_str(json)?)
+}
+
+// Puzzles unlock at midnight US Eastern time (UTC-5) on December 1st to 25th.
+pub fn unlock_timestamp(year: i64, day: u8) -> i64 {
+    days_from_civil(year, 12, day as i64) * 86400 + 5 * 3600
+}
+
+// Howard Hinnant's days_from_civil: days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+// The site's local score: for every star, the first member to get it scores one point per
+// member on the board, the second one point less, and so on.
+pub fn local_scores(leaderboard: &Leaderboard) -> HashMap<u64, u64> {
+    let member_count = leaderboard.members.len() as u64;
+    let mut scores = leaderboard
+        .members
+        .values()
+        .map(|m| (m.id, 0))
+        .collect::<HashMap<_, _>>();
+
+    for day in 1..=25 {
+        for part in 1..=2 {
+            let mut finishers = leaderboard
+                .members
+                .values()
+                .filter_map(|m| {
+                    m.star(day, part)
+                        .map(|s| (s.get_star_ts, s.star_index, m.id))
+                })
+                .collect::<Vec<_>>();
+            finishers.sort();
+            for (rank, (_, _, id)) in finishers.iter().enumerate() {
+                *scores.get_mut(id).unwrap() += member_count - rank as u64;
+            }
+        }
+    }
+
+    scores
+}
+
+pub fn format_duration(seconds: i64) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+pub fn report(leaderboard: &Leaderboard, day: Option<u8>) -> Result<String, Box<dyn Error>> {
+    let year = leaderboard.event.parse::<i64>()?;
+    let scores = local_scores(leaderboard);
+
+    let mut members = leaderboard.members.values().collect::<Vec<_>>();
+    members.sort_by(|a, b| {
+        scores[&b.id]
+            .cmp(&scores[&a.id])
+            .then(b.stars.cmp(&a.stars))
+            .then(a.id.cmp(&b.id))
+    });
+
+    let mut out = String::new();
+    writeln!(out, "{year} private leaderboard")?;
+    writeln!(out, "{:>4} {:>6} {:>6}  name", "rank", "score", "stars")?;
+    for (rank, member) in members.iter().enumerate() {
+        writeln!(
+            out,
+            "{:>4} {:>6} {:>6}  {}",
+            rank + 1,
+            scores[&member.id],
+            member.stars,
+            member.display_name()
+        )?;
+    }
+
+    let days = match day {
+        Some(day) => vec![day],
+        None => (1..=25).collect(),
+    };
+    let show = |time: Option<i64>| time.map_or(String::from("-"), format_duration);
+    for day in days {
+        if !members.iter().any(|m| m.star(day, 1).is_some()) {
+            continue;
+        }
+        writeln!(out, "\nday {day}")?;
+        writeln!(
+            out,
+            "{:>12} {:>12} {:>12}  name",
+            "part 1", "part 2", "delta"
+        )?;
+        for member in members.iter() {
+            let times = member.times(year, day);
+            if times.part_1.is_none() {
+                continue;
+            }
+            writeln!(
+                out,
+                "{:>12} {:>12} {:>12}  {}",
+                show(times.part_1),
+                show(times.part_2),
+                show(times.delta()),
+                member.display_name()
+            )?;
+        }
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::mock::MockClient;
+
+    // 2023 day 1 unlocked at 1701406800 and day 2 at 1701493200.
+    const BOARD: &str = r#"{
+        "event": "2023",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "alice", "stars": 3, "local_score": 0, "global_score": 0,
+                "last_star_ts": 1701493500,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1701407100, "star_index": 10},
+                        "2": {"get_star_ts": 1701408000, "star_index": 30}
+                    },
+                    "2": {"1": {"get_star_ts": 1701493500, "star_index": 50}}
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 2, "local_score": 0, "global_score": 0,
+                "last_star_ts": 1701407700,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1701407000, "star_index": 5},
+                        "2": {"get_star_ts": 1701407700, "star_index": 20}
+                    }
+                }
+            },
+            "3": {
+                "id": 3, "name": "carol", "stars": 0, "local_score": 0, "global_score": 0,
+                "last_star_ts": 0, "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn unlock_times() {
+        assert_eq!(unlock_timestamp(2023, 1), 1701406800);
+        assert_eq!(unlock_timestamp(2023, 2), 1701493200);
+        assert_eq!(unlock_timestamp(2015, 25), 1451019600);
+    }
+
+    #[test]
+    fn part_times_and_delta() {
+        let board = parse(BOARD).unwrap();
+        let alice = &board.members["1"];
+        let times = alice.times(2023, 1);
+        assert_eq!(times.part_1, Some(300));
+        assert_eq!(times.part_2, Some(1200));
+        assert_eq!(times.delta(), Some(900));
+        assert_eq!(alice.times(2023, 2).delta(), None);
+        assert_eq!(board.members["2"].display_name(), "(anonymous user #2)");
+    }
+
+    #[test]
+    fn local_score_like_the_site() {
+        let board = parse(BOARD).unwrap();
+        let scores = local_scores(&board);
+        // Day 1: member 2 finishes both parts first (3 + 3), alice second (2 + 2).
+        // Day 2 part 1: alice alone (3).
+        assert_eq!(scores[&1], 7);
+        assert_eq!(scores[&2], 6);
+        assert_eq!(scores[&3], 0);
+    }
+
+    #[test]
+    fn report_ranks_members() {
+        let board = parse(BOARD).unwrap();
+        let report = report(&board, Some(1)).unwrap();
+        let lines = report.lines().collect::<Vec<_>>();
+        assert_eq!(lines[2], "   1      7      3  alice");
+        assert_eq!(lines[3], "   2      6      2  (anonymous user #2)");
+        assert_eq!(lines[4], "   3      0      0  carol");
+        assert_eq!(lines[8], "     0:05:00      0:20:00      0:15:00  alice");
+        assert_eq!(lines.len(), 10);
+    }
+
+    #[test]
+    fn fetch_through_client() {
+        let client = MockClient {
+            pages: HashMap::from([(
+                String::from("https://adventofcode.com/2023/leaderboard/private/view/42.json"),
+                String::from(BOARD),
+            )]),
+        };
+        let board = fetch(&client, 2023, "42").unwrap();
+        assert_eq!(board.members.len(), 3);
+        assert!(fetch(&client, 2023, "7").is_err());
+    }
+}
@@ -0,0 +1,11 @@
+pub mod cache;
+pub mod compare;
+pub mod data;
+pub mod describe;
+pub mod http;
+pub mod leaderboard;
+pub mod plugins;
+pub mod server;
+pub mod solutions;
+pub mod vault;
+pub mod watch;
@@ -0,0 +1,624 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+
+use aoc::cache::{self, Cache};
+use aoc::compare;
+use aoc::data;
+use aoc::describe;
+use aoc::http::AocClient;
+use aoc::leaderboard;
+use aoc::plugins::{self, Plugin};
+use aoc::server::{self, Config};
+use aoc::solutions;
+use aoc::vault::{self, Vault};
+use aoc::watch;
+use explore::App;
+use visualize::{output, Recorder, Visualize};
+
+const USAGE: &str = "usage:
+    aoc run <year> <day> [--input FILE] [--data DIR] [--no-cache] [--plugins DIR]
+    aoc watch --day N [--year YEAR] [--data DIR] [--root DIR] [--timeout SECS]
+    aoc plugins [--data DIR]    (lists solutions in <data>/plugins)
+    aoc diff <year> <day> [--input FILE]... [--data DIR]    (checks every implementation agrees)
+    aoc bench <year> <day> [--input FILE] [--data DIR] [--runs N]
+    aoc inputs (lock | unlock | keygen) [--data DIR]    (key from AOC_INPUT_KEY or AOC_INPUT_KEY_FILE)
+    aoc describe <year> <day> [--root DIR] [--force]    (saves examples to <root>/<year>/day<N>/fixtures)
+    aoc leaderboard <year> (--file FILE | --id ID) [--day N]    (--id needs AOC_SESSION)
+    aoc serve [--addr ADDR] [--timeout SECS] [--max-body BYTES] [--workers N]
+    aoc solve <year> <day> <part>    (reads the puzzle input from stdin)
+    aoc explore <year> <day> <input>    (terminal UI for 2023 day 3 and day 10)
+    aoc visualize <year> <day> <input> <out.gif|frames-dir> [--every N] [--scale N] [--steps N]";
+
+enum Command {
+    Run(Puzzle),
+    Diff(Comparison),
+    Bench(Comparison),
+    Leaderboard(Board),
+    Describe(PuzzlePage),
+    Inputs { action: String, data: PathBuf },
+    Plugins { data: PathBuf },
+    Serve(Config),
+    Solve { year: u16, day: u8, part: u8 },
+    Watch(watch::Config),
+    Visualize(Animation),
+    Explore { year: u16, day: u8, input: PathBuf },
+}
+
+struct Puzzle {
+    year: u16,
+    day: u8,
+    input: Option<PathBuf>,
+    data: PathBuf,
+    use_cache: bool,
+    plugins: Option<PathBuf>,
+}
+
+struct Comparison {
+    year: u16,
+    day: u8,
+    inputs: Vec<PathBuf>,
+    data: PathBuf,
+    runs: u32,
+}
+
+struct Board {
+    year: u16,
+    source: BoardSource,
+    day: Option<u8>,
+}
+
+struct PuzzlePage {
+    year: u16,
+    day: u8,
+    root: PathBuf,
+    // Replaces examples saved earlier.
+    force: bool,
+}
+
+enum BoardSource {
+    File(PathBuf),
+    Id(String),
+}
+
+struct Animation {
+    year: u16,
+    day: u8,
+    input: PathBuf,
+    output: PathBuf,
+    every: usize,
+    scale: usize,
+    steps: usize,
+}
+
+fn main() {
+    let command = read_input(env::args()).unwrap_or_else(|err| {
+        println!("Problem parsing arguments: {err}\n{USAGE}");
+        process::exit(1);
+    });
+
+    run(command).unwrap_or_else(|err| {
+        println!("Calculation error: {err}");
+        process::exit(1);
+    });
+}
+
+fn read_input(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    args.next();
+
+    match args.next().as_deref() {
+        Some("run") => read_run_options(args).map(Command::Run),
+        Some("diff") => read_comparison_options(args).map(Command::Diff),
+        Some("bench") => read_comparison_options(args).map(Command::Bench),
+        Some("describe") => read_describe_options(args).map(Command::Describe),
+        Some("inputs") => {
+            let action = args.next().ok_or("Didn't get lock, unlock or keygen")?;
+            if !["lock", "unlock", "keygen"].contains(&action.as_str()) {
+                return Err(format!("unknown inputs action '{action}'"));
+            }
+            let data = match (args.next().as_deref(), args.next()) {
+                (None, _) => PathBuf::from("."),
+                (Some("--data"), Some(data)) => PathBuf::from(data),
+                (Some(other), _) => return Err(format!("unknown option '{other}'")),
+            };
+            Ok(Command::Inputs { action, data })
+        }
+        Some("plugins") => {
+            let data = match (args.next().as_deref(), args.next()) {
+                (None, _) => PathBuf::from("."),
+                (Some("--data"), Some(data)) => PathBuf::from(data),
+                (Some(other), _) => return Err(format!("unknown option '{other}'")),
+            };
+            Ok(Command::Plugins { data })
+        }
+        Some("leaderboard") => read_leaderboard_options(args).map(Command::Leaderboard),
+        Some("serve") => read_serve_options(args).map(Command::Serve),
+        Some("solve") => {
+            let year = read_number(args.next(), "year")?;
+            let day = read_number(args.next(), "day")?;
+            let part = read_number(args.next(), "part")?;
+            Ok(Command::Solve { year, day, part })
+        }
+        Some("explore") => {
+            let year = read_number(args.next(), "year")?;
+            let day = read_number(args.next(), "day")?;
+            let input = PathBuf::from(args.next().ok_or("Didn't get an input file")?);
+            Ok(Command::Explore { year, day, input })
+        }
+        Some("watch") => read_watch_options(args).map(Command::Watch),
+        Some("visualize") => read_visualize_options(args).map(Command::Visualize),
+        Some(other) => Err(format!("unknown command '{other}'")),
+        None => Err(String::from("Didn't get a command")),
+    }
+}
+
+fn read_run_options(mut args: impl Iterator<Item = String>) -> Result<Puzzle, String> {
+    let mut puzzle = Puzzle {
+        year: read_number(args.next(), "year")?,
+        day: read_number(args.next(), "day")?,
+        input: None,
+        data: PathBuf::from("."),
+        use_cache: true,
+        plugins: None,
+    };
+
+    while let Some(option) = args.next() {
+        match option.as_str() {
+            "--input" => {
+                puzzle.input = Some(PathBuf::from(args.next().ok_or("--input needs a value")?))
+            }
+            "--data" => puzzle.data = PathBuf::from(args.next().ok_or("--data needs a value")?),
+            "--no-cache" => puzzle.use_cache = false,
+            "--plugins" => {
+                puzzle.plugins = Some(PathBuf::from(args.next().ok_or("--plugins needs a value")?))
+            }
+            other => return Err(format!("unknown option '{other}'")),
+        }
+    }
+
+    Ok(puzzle)
+}
+
+fn read_comparison_options(mut args: impl Iterator<Item = String>) -> Result<Comparison, String> {
+    let mut comparison = Comparison {
+        year: read_number(args.next(), "year")?,
+        day: read_number(args.next(), "day")?,
+        inputs: vec![],
+        data: PathBuf::from("."),
+        runs: 10,
+    };
+
+    while let Some(option) = args.next() {
+        match option.as_str() {
+            "--input" => comparison
+                .inputs
+                .push(PathBuf::from(args.next().ok_or("--input needs a value")?)),
+            "--data" => comparison.data = PathBuf::from(args.next().ok_or("--data needs a value")?),
+            "--runs" => comparison.runs = read_number(args.next(), "--runs")?,
+            other => return Err(format!("unknown option '{other}'")),
+        }
+    }
+
+    Ok(comparison)
+}
+
+fn read_leaderboard_options(mut args: impl Iterator<Item = String>) -> Result<Board, String> {
+    let year = read_number(args.next(), "year")?;
+    let mut source = None;
+    let mut day = None;
+
+    while let Some(option) = args.next() {
+        match option.as_str() {
+            "--file" => {
+                source = Some(BoardSource::File(PathBuf::from(
+                    args.next().ok_or("--file needs a value")?,
+                )))
+            }
+            "--id" => source = Some(BoardSource::Id(args.next().ok_or("--id needs a value")?)),
+            "--day" => day = Some(read_number(args.next(), "--day")?),
+            other => return Err(format!("unknown option '{other}'")),
+        }
+    }
+
+    let source = source.ok_or("Didn't get a leaderboard --file or --id")?;
+    Ok(Board { year, source, day })
+}
+
+fn read_describe_options(mut args: impl Iterator<Item = String>) -> Result<PuzzlePage, String> {
+    let mut page = PuzzlePage {
+        year: read_number(args.next(), "year")?,
+        day: read_number(args.next(), "day")?,
+        root: PathBuf::from("."),
+        force: false,
+    };
+
+    while let Some(option) = args.next() {
+        match option.as_str() {
+            "--root" => page.root = PathBuf::from(args.next().ok_or("--root needs a value")?),
+            "--force" => page.force = true,
+            other => return Err(format!("unknown option '{other}'")),
+        }
+    }
+
+    Ok(page)
+}
+
+fn read_serve_options(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+    let mut config = Config {
+        addr: String::from("127.0.0.1:8023"),
+        max_body: 1024 * 1024,
+        timeout: Duration::from_secs(10),
+        workers: 4,
+        exe: env::current_exe().map_err(|err| err.to_string())?,
+    };
+
+    while let Some(option) = args.next() {
+        match option.as_str() {
+            "--addr" => config.addr = args.next().ok_or("--addr needs a value")?,
+            "--timeout" => {
+                config.timeout = Duration::from_secs(read_number(args.next(), "--timeout")?)
+            }
+            "--max-body" => config.max_body = read_number(args.next(), "--max-body")?,
+            "--workers" => config.workers = read_number(args.next(), "--workers")?,
+            other => return Err(format!("unknown option '{other}'")),
+        }
+    }
+
+    Ok(config)
+}
+
+fn read_watch_options(mut args: impl Iterator<Item = String>) -> Result<watch::Config, String> {
+    let mut day = None;
+    let mut config = watch::Config {
+        year: 2023,
+        day: 0,
+        data: PathBuf::from("."),
+        root: PathBuf::from("."),
+        timeout: Duration::from_secs(10),
+        exe: env::current_exe().map_err(|err| err.to_string())?,
+    };
+
+    while let Some(option) = args.next() {
+        match option.as_str() {
+            "--day" => day = Some(read_number(args.next(), "--day")?),
+            "--year" => config.year = read_number(args.next(), "--year")?,
+            "--data" => config.data = PathBuf::from(args.next().ok_or("--data needs a value")?),
+            "--root" => config.root = PathBuf::from(args.next().ok_or("--root needs a value")?),
+            "--timeout" => {
+                config.timeout = Duration::from_secs(read_number(args.next(), "--timeout")?)
+            }
+            other => return Err(format!("unknown option '{other}'")),
+        }
+    }
+
+    config.day = day.ok_or("Didn't get a --day")?;
+    if solutions::find(config.year, config.day).is_none() {
+        return Err(format!(
+            "no solution for {} day {}",
+            config.year, config.day
+        ));
+    }
+    Ok(config)
+}
+
+fn read_visualize_options(mut args: impl Iterator<Item = String>) -> Result<Animation, String> {
+    let mut animation = Animation {
+        year: read_number(args.next(), "year")?,
+        day: read_number(args.next(), "day")?,
+        input: PathBuf::from(args.next().ok_or("Didn't get an input file")?),
+        output: PathBuf::from(args.next().ok_or("Didn't get an output path")?),
+        every: 1,
+        scale: 4,
+        steps: 200,
+    };
+
+    while let Some(option) = args.next() {
+        match option.as_str() {
+            "--every" => animation.every = read_number(args.next(), "--every")?,
+            "--scale" => animation.scale = read_number(args.next(), "--scale")?,
+            "--steps" => animation.steps = read_number(args.next(), "--steps")?,
+            other => return Err(format!("unknown option '{other}'")),
+        }
+    }
+    if animation.scale == 0 {
+        return Err("--scale must be at least 1".to_string());
+    }
+
+    Ok(animation)
+}
+
+fn read_number<T: std::str::FromStr>(arg: Option<String>, name: &str) -> Result<T, String> {
+    let arg = arg.ok_or(format!("Didn't get a {name}"))?;
+    arg.parse::<T>()
+        .map_err(|_| format!("'{arg}' is not a valid {name}"))
+}
+
+fn run(command: Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Run(puzzle) => solve_puzzle(&puzzle)?,
+        Command::Diff(comparison) => diff(&comparison)?,
+        Command::Bench(comparison) => bench(&comparison)?,
+        Command::Leaderboard(board) => {
+            let leaderboard = match &board.source {
+                BoardSource::File(path) => leaderboard::parse(&fs::read_to_string(path)?)?,
+                BoardSource::Id(id) => leaderboard::fetch(&AocClient::from_env(), board.year, id)?,
+            };
+            print!("{}", leaderboard::report(&leaderboard, board.day)?);
+        }
+        Command::Describe(page) => {
+            let description = describe::fetch(&AocClient::from_env(), page.year, page.day)?;
+            println!("{}", description.markdown);
+
+            let dir = describe::fixture_dir(&page.root, page.year, page.day);
+            for path in describe::save_examples(&description.examples, &dir, page.force)? {
+                eprintln!("saved {}", path.display());
+            }
+        }
+        Command::Inputs { action, data } => lock_inputs(&action, &data)?,
+        Command::Plugins { data } => {
+            for plugin in load_plugins(&data::plugin_dir(&data)) {
+                println!("{}", plugin.path.display());
+                for solution in &plugin.solutions {
+                    println!(
+                        "    {} day {} part {}: {}",
+                        solution.year, solution.day, solution.part, solution.name
+                    );
+                }
+            }
+        }
+        Command::Serve(config) => {
+            let server = server::bind(&config).map_err(|err| err.to_string())?;
+            println!("listening on http://{}", server.server_addr());
+            server::serve(server, config);
+        }
+        Command::Solve { year, day, part } => {
+            let solver = solutions::find_solver(year, day, part)
+                .ok_or(format!("no solution for {year} day {day} part {part}"))?;
+            let mut contents = String::new();
+            io::stdin().read_to_string(&mut contents)?;
+
+            let start = Instant::now();
+            let answer = solver(&contents);
+            println!("{answer}\n{}", start.elapsed().as_nanos());
+        }
+        Command::Visualize(animation) => animate(&animation)?,
+        Command::Watch(config) => watch::watch(&config)?,
+        Command::Explore { year, day, input } => {
+            let contents = fs::read_to_string(input)?;
+            let puzzle: Box<dyn explore::Puzzle> = match (year, day) {
+                (2023, 3) => Box::new(explore::day3::Schematic::new(&contents)),
+                (2023, 10) => Box::new(explore::day10::PipeMaze::new(&contents)?),
+                (year, day) => return Err(format!("no explorer for {year} day {day}").into()),
+            };
+            explore::run(App::new(puzzle))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn solve_puzzle(puzzle: &Puzzle) -> Result<(), Box<dyn Error>> {
+    let solution = solutions::find(puzzle.year, puzzle.day).ok_or(format!(
+        "no solution for {} day {}",
+        puzzle.year, puzzle.day
+    ))?;
+    let input = match &puzzle.input {
+        Some(input) => input.clone(),
+        None => data::input_path(&puzzle.data, puzzle.year, puzzle.day),
+    };
+    let vault = Vault::from_env(&puzzle.data)?;
+    let contents = vault::read_input(&input, vault.as_ref())?;
+    let answers = data::read_answers(&data::answer_path(&puzzle.data, puzzle.year, puzzle.day))?;
+    let cache = Cache::new(data::cache_dir(&puzzle.data));
+
+    println!("{} day {}", puzzle.year, puzzle.day);
+    for (part, solver) in [solution.part_1, solution.part_2].iter().enumerate() {
+        let key = cache::key(puzzle.year, puzzle.day, part as u8 + 1, &contents);
+        let cached = if puzzle.use_cache {
+            cache.get(&key)?
+        } else {
+            None
+        };
+
+        let (answer, timing) = match cached {
+            Some(answer) => (answer, String::from("cached")),
+            None => {
+                let start = Instant::now();
+                let answer = solver(&contents);
+                let elapsed = start.elapsed();
+                cache.put(&key, &answer)?;
+                (answer, format!("{elapsed:?}"))
+            }
+        };
+
+        let check = match &answers[part] {
+            Some(expected) if *expected == answer => String::from(" ok"),
+            Some(expected) => format!(" WRONG, expected {expected}"),
+            None => String::new(),
+        };
+        println!("part {}: {answer} ({timing}){check}", part + 1);
+    }
+
+    // Plugin answers aren't cached: the runner can't tell when a plugin was rebuilt.
+    let plugin_dir = match &puzzle.plugins {
+        Some(dir) => dir.clone(),
+        None => data::plugin_dir(&puzzle.data),
+    };
+    for plugin in load_plugins(&plugin_dir) {
+        for solution in plugin.find(puzzle.year, puzzle.day) {
+            let start = Instant::now();
+            let result = plugin.solve(solution, &contents);
+            let elapsed = start.elapsed();
+
+            let line = match result {
+                Ok(answer) => {
+                    let check = match &answers[solution.part as usize - 1] {
+                        Some(expected) if *expected == answer => String::from(" ok"),
+                        Some(expected) => format!(" WRONG, expected {expected}"),
+                        None => String::new(),
+                    };
+                    format!("{answer} ({elapsed:?}){check}")
+                }
+                Err(err) => format!("failed: {err}"),
+            };
+            println!("part {} [{}]: {line}", solution.part, solution.name);
+        }
+    }
+
+    Ok(())
+}
+
+fn comparison_inputs(comparison: &Comparison) -> Result<Vec<(PathBuf, String)>, Box<dyn Error>> {
+    let paths = if comparison.inputs.is_empty() {
+        vec![data::input_path(
+            &comparison.data,
+            comparison.year,
+            comparison.day,
+        )]
+    } else {
+        comparison.inputs.clone()
+    };
+    let vault = Vault::from_env(&comparison.data)?;
+    paths
+        .into_iter()
+        .map(|path| {
+            let contents = vault::read_input(&path, vault.as_ref())?;
+            Ok((path, contents))
+        })
+        .collect()
+}
+
+fn diff(comparison: &Comparison) -> Result<(), Box<dyn Error>> {
+    let solution = solutions::find(comparison.year, comparison.day).ok_or(format!(
+        "no solution for {} day {}",
+        comparison.year, comparison.day
+    ))?;
+    let inputs = comparison_inputs(comparison)?;
+    let mut disagreements = 0;
+
+    for (path, contents) in inputs.iter() {
+        for part in 1..=2 {
+            let outcomes = compare::run_all(&solution.implementations(part), contents);
+            let agree = compare::agree(&outcomes);
+            println!(
+                "{} day {} part {part} ({}): {}",
+                solution.year,
+                solution.day,
+                path.display(),
+                if agree { "agree" } else { "DISAGREE" }
+            );
+            for outcome in outcomes {
+                let answer = match outcome.answer {
+                    Ok(answer) => answer,
+                    Err(err) => err,
+                };
+                println!("    {:<12} {answer}", outcome.name);
+            }
+            if !agree {
+                disagreements += 1;
+            }
+        }
+    }
+
+    if disagreements > 0 {
+        return Err(format!("implementations disagree on {disagreements} part(s)").into());
+    }
+    Ok(())
+}
+
+fn bench(comparison: &Comparison) -> Result<(), Box<dyn Error>> {
+    let solution = solutions::find(comparison.year, comparison.day).ok_or(format!(
+        "no solution for {} day {}",
+        comparison.year, comparison.day
+    ))?;
+    let inputs = comparison_inputs(comparison)?;
+
+    for (path, contents) in inputs.iter() {
+        println!(
+            "{} day {} ({}), best and mean of {} runs",
+            solution.year,
+            solution.day,
+            path.display(),
+            comparison.runs
+        );
+        for part in 1..=2 {
+            let implementations = solution.implementations(part);
+            for timing in compare::bench(&implementations, contents, comparison.runs) {
+                println!(
+                    "    part {part} {:<12} {:>12} {:>12}",
+                    timing.name,
+                    format!("{:?}", timing.min),
+                    format!("{:?}", timing.mean)
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn load_plugins(dir: &Path) -> Vec<Plugin> {
+    let (plugins, failures) = plugins::load_dir(dir);
+    for failure in failures {
+        eprintln!(
+            "skipping plugin {}: {}",
+            failure.path.display(),
+            failure.error
+        );
+    }
+    plugins
+}
+
+fn lock_inputs(action: &str, data: &Path) -> Result<(), Box<dyn Error>> {
+    if action == "keygen" {
+        println!("{}", Vault::generate_key());
+        return Ok(());
+    }
+
+    let vault = Vault::from_env(data)?.ok_or(format!(
+        "no input key; set {} or {}",
+        vault::KEY_ENV,
+        vault::KEY_FILE_ENV
+    ))?;
+    let inputs = data.join("inputs");
+    let files = match action {
+        "lock" => vault::lock(&inputs, &vault)?,
+        _ => vault::unlock(&inputs, &vault)?,
+    };
+    for file in files {
+        println!("{action}ed {}", file.display());
+    }
+
+    Ok(())
+}
+
+fn animate(animation: &Animation) -> Result<(), Box<dyn Error>> {
+    let contents = fs::read_to_string(&animation.input)?;
+    let solver: Box<dyn Visualize> = match (animation.year, animation.day) {
+        (2023, 5) => Box::new(visualize::day5::SeedRanges::new(&contents, 512)),
+        (2023, 8) => Box::new(visualize::day8::Network::new(&contents, animation.steps)),
+        (2023, 10) => Box::new(visualize::day10::PipeMaze::new(&contents)),
+        (year, day) => return Err(format!("no visualization for {year} day {day}").into()),
+    };
+
+    let mut recorder = Recorder::new(animation.every);
+    solver.visualize(&mut recorder);
+    let frames = recorder.frames();
+
+    if animation.output.extension().is_some_and(|ext| ext == "gif") {
+        output::write_gif(&frames, &animation.output, animation.scale, 5)?;
+    } else {
+        output::write_png_sequence(&frames, &animation.output, animation.scale)?;
+    }
+    println!(
+        "wrote {} frames to {}",
+        frames.len(),
+        animation.output.display()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use libloading::Library;
+use plugin_api::{AbiVersionFn, CountFn, InfoFn, SolutionInfo, SolveFn, ABI_VERSION};
+use std::ffi::{c_void, CStr};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Extra solutions loaded at runtime from shared libraries built against `plugin_api`.
+pub struct Plugin {
+    pub path: PathBuf,
+    pub solutions: Vec<PluginSolution>,
+    solve: SolveFn,
+    // Keeps `solve` valid; never read.
+    _library: Library,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PluginSolution {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub name: String,
+    index: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct LoadFailure {
+    pub path: PathBuf,
+    pub error: String,
+}
+
+pub fn check_abi_version(version: u32) -> Result<(), String> {
+    if version != ABI_VERSION {
+        return Err(format!(
+            "plugin ABI version {version} doesn't match the runner's version {ABI_VERSION}"
+        ));
+    }
+    Ok(())
+}
+
+impl Plugin {
+    pub fn load(path: &Path) -> Result<Plugin, String> {
+        // Loading runs the library's initializers, so only load files someone put in the plugin
+        // directory on purpose.
+        let library = unsafe { Library::new(path) }.map_err(|err| err.to_string())?;
+        unsafe {
+            let abi_version = *library
+                .get::<AbiVersionFn>(b"aoc_plugin_abi_version")
+                .map_err(|_| "not an aoc plugin: missing aoc_plugin_abi_version")?;
+            check_abi_version(abi_version())?;
+
+            let count = *symbol::<CountFn>(&library, "aoc_plugin_count")?;
+            let info = *symbol::<InfoFn>(&library, "aoc_plugin_info")?;
+            let solve = *symbol::<SolveFn>(&library, "aoc_plugin_solve")?;
+
+            let solutions = (0..count())
+                .map(|index| read_info(info(index), index))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(Plugin {
+                path: path.to_owned(),
+                solutions,
+                solve,
+                _library: library,
+            })
+        }
+    }
+
+    pub fn solve(&self, solution: &PluginSolution, contents: &str) -> Result<String, String> {
+        let mut out = Vec::<u8>::new();
+        let status = unsafe {
+            (self.solve)(
+                solution.index,
+                contents.as_ptr(),
+                contents.len(),
+                write_to_vec,
+                &mut out as *mut Vec<u8> as *mut c_void,
+            )
+        };
+        let message = String::from_utf8_lossy(&out).into_owned();
+
+        match status {
+            plugin_api::SOLVED => Ok(message),
+            plugin_api::FAILED => Err(format!("solver panicked: {message}")),
+            plugin_api::NO_SUCH_SOLUTION => Err(String::from("no such solution")),
+            plugin_api::INVALID_INPUT => Err(message),
+            status => Err(format!("unknown status {status}")),
+        }
+    }
+
+    pub fn find(&self, year: u16, day: u8) -> impl Iterator<Item = &PluginSolution> {
+        self.solutions
+            .iter()
+            .filter(move |s| s.year == year && s.day == day)
+    }
+}
+
+unsafe fn symbol<'lib, T>(
+    library: &'lib Library,
+    name: &str,
+) -> Result<libloading::Symbol<'lib, T>, String> {
+    library
+        .get::<T>(name.as_bytes())
+        .map_err(|_| format!("missing symbol {name}"))
+}
+
+unsafe fn read_info(info: *const SolutionInfo, index: usize) -> Result<PluginSolution, String> {
+    let info = info
+        .as_ref()
+        .ok_or(format!("no info for solution {index}"))?;
+    if info.name.is_null() {
+        return Err(format!("solution {index} has no name"));
+    }
+    let name = CStr::from_ptr(info.name)
+        .to_str()
+        .map_err(|_| format!("solution {index} has a name that isn't UTF-8"))?;
+    if !(1..=2).contains(&info.part) {
+        return Err(format!("solution '{name}' is for part {}", info.part));
+    }
+
+    Ok(PluginSolution {
+        year: info.year,
+        day: info.day,
+        part: info.part,
+        name: name.to_owned(),
+        index,
+    })
+}
+
+extern "C" fn write_to_vec(ctx: *mut c_void, ptr: *const u8, len: usize) {
+    let out = unsafe { &mut *(ctx as *mut Vec<u8>) };
+    out.extend_from_slice(unsafe { std::slice::from_raw_parts(ptr, len) });
+}
+
+// Loads every shared library in `dir`. A plugin that fails to load is reported and skipped
+// rather than stopping the others.
+pub fn load_dir(dir: &Path) -> (Vec<Plugin>, Vec<LoadFailure>) {
+    let mut plugins = vec![];
+    let mut failures = vec![];
+    let Ok(entries) = fs::read_dir(dir) else {
+        return (plugins, failures);
+    };
+
+    let mut paths = entries
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext == std::env::consts::DLL_EXTENSION)
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    for path in paths {
+        match Plugin::load(&path) {
+            Ok(plugin) => plugins.push(plugin),
+            Err(error) => failures.push(LoadFailure { path, error }),
+        }
+    }
+    (plugins, failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reject_other_abi_versions() {
+        assert!(check_abi_version(ABI_VERSION).is_ok());
+        assert_eq!(
+            check_abi_version(ABI_VERSION + 1),
+            Err(format!(
+                "plugin ABI version {} doesn't match the runner's version {ABI_VERSION}",
+                ABI_VERSION + 1
+            ))
+        );
+    }
+
+    #[test]
+    fn report_files_that_are_not_plugins() {
+        let dir = tempfile::tempdir().unwrap();
+        let broken = dir
+            .path()
+            .join(format!("broken.{}", std::env::consts::DLL_EXTENSION));
+        fs::write(&broken, "not a library").unwrap();
+        fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let (plugins, failures) = load_dir(dir.path());
+        assert!(plugins.is_empty());
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].path, broken);
+
+        assert!(load_dir(&dir.path().join("missing")).1.is_empty());
+    }
+}
@@ -0,0 +1,261 @@
+use serde_json::json;
+use std::error::Error;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::solutions;
+
+pub struct Config {
+    pub addr: String,
+    pub max_body: usize,
+    pub timeout: Duration,
+    pub workers: usize,
+    // Solvers run in a child `aoc solve` process so that a runaway one (day8
+    // `navigate` never reaching its end node) can be killed on timeout.
+    pub exe: PathBuf,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Answer {
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SolveError {
+    Timeout,
+    Failed(String),
+}
+
+pub fn bind(config: &Config) -> Result<Server, Box<dyn Error + Send + Sync>> {
+    Server::http(&config.addr)
+}
+
+pub fn serve(server: Server, config: Config) {
+    let server = Arc::new(server);
+    let config = Arc::new(config);
+
+    let workers = (0..config.workers.max(1))
+        .map(|_| {
+            let server = Arc::clone(&server);
+            let config = Arc::clone(&config);
+            thread::spawn(move || {
+                while let Ok(request) = server.recv() {
+                    handle_request(request, &config);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for worker in workers {
+        let _ = worker.join();
+    }
+}
+
+fn handle_request(mut request: Request, config: &Config) {
+    let (status, body) = route(&mut request, config);
+    let header = Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap();
+    let response = Response::from_string(body.to_string())
+        .with_status_code(status)
+        .with_header(header);
+    let _ = request.respond(response);
+}
+
+fn route(request: &mut Request, config: &Config) -> (u16, serde_json::Value) {
+    let (year, day, part) = match parse_path(request.url()) {
+        Some(path) => path,
+        None => {
+            return error(
+                404,
+                "expected /solve/{year}/{day}/{part} or /solve/{day}/{part}",
+            )
+        }
+    };
+    if *request.method() != Method::Post {
+        return error(405, "only POST is supported");
+    }
+    if solutions::find_solver(year, day, part).is_none() {
+        return error(
+            404,
+            &format!("no solution for {year} day {day} part {part}"),
+        );
+    }
+
+    let input = match read_body(request, config.max_body) {
+        Ok(input) => input,
+        Err(response) => return response,
+    };
+
+    match solve_in_child(&config.exe, year, day, part, &input, config.timeout) {
+        Ok(answer) => (
+            200,
+            json!({
+                "year": year,
+                "day": day,
+                "part": part,
+                "answer": answer.answer,
+                "elapsed_ms": answer.elapsed.as_secs_f64() * 1000.0,
+            }),
+        ),
+        Err(SolveError::Timeout) => error(
+            504,
+            &format!("solver did not finish within {:?}", config.timeout),
+        ),
+        Err(SolveError::Failed(reason)) => error(422, &reason),
+    }
+}
+
+// `/solve/{year}/{day}/{part}`, or `/solve/{day}/{part}` for 2023.
+fn parse_path(url: &str) -> Option<(u16, u8, u8)> {
+    let segments = url.trim_matches('/').split('/').collect::<Vec<_>>();
+    let (year, day, part) = match segments[..] {
+        ["solve", year, day, part] => (year.parse::<u16>().ok()?, day, part),
+        ["solve", day, part] => (2023, day, part),
+        _ => return None,
+    };
+    Some((year, day.parse::<u8>().ok()?, part.parse::<u8>().ok()?))
+}
+
+fn read_body(request: &mut Request, max_body: usize) -> Result<String, (u16, serde_json::Value)> {
+    let too_large = || error(413, &format!("input is larger than {max_body} bytes"));
+    if request.body_length().is_some_and(|len| len > max_body) {
+        return Err(too_large());
+    }
+
+    let mut body = vec![];
+    request
+        .as_reader()
+        .take(max_body as u64 + 1)
+        .read_to_end(&mut body)
+        .map_err(|err| error(400, &err.to_string()))?;
+    if body.len() > max_body {
+        return Err(too_large());
+    }
+
+    String::from_utf8(body).map_err(|_| error(400, "input is not valid UTF-8"))
+}
+
+fn error(status: u16, message: &str) -> (u16, serde_json::Value) {
+    (status, json!({ "error": message }))
+}
+
+pub fn solve_in_child(
+    exe: &Path,
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    timeout: Duration,
+) -> Result<Answer, SolveError> {
+    let mut child = Command::new(exe)
+        .args([
+            "solve",
+            &year.to_string(),
+            &day.to_string(),
+            &part.to_string(),
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| SolveError::Failed(err.to_string()))?;
+
+    // The child reads all of stdin before solving, so this cannot deadlock.
+    let written = child.stdin.take().unwrap().write_all(input.as_bytes());
+
+    // Both pipes are drained while the child runs, so one that writes more than a pipe buffer
+    // holds doesn't block until the deadline.
+    let stdout = drain(child.stdout.take().unwrap());
+    let stderr = drain(child.stderr.take().unwrap());
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(SolveError::Timeout);
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(5)),
+            Err(err) => return Err(SolveError::Failed(err.to_string())),
+        }
+    };
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    if !status.success() || written.is_err() {
+        let stderr = String::from_utf8_lossy(&stderr);
+        return Err(SolveError::Failed(panic_message(&stderr)));
+    }
+
+    let stdout = String::from_utf8_lossy(&stdout);
+    let mut lines = stdout.lines();
+    let answer = lines.next().unwrap_or_default().to_owned();
+    let nanos = lines
+        .next()
+        .and_then(|n| n.parse::<u64>().ok())
+        .unwrap_or_default();
+
+    Ok(Answer {
+        answer,
+        elapsed: Duration::from_nanos(nanos),
+    })
+}
+
+fn drain(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = vec![];
+        let _ = pipe.read_to_end(&mut bytes);
+        bytes
+    })
+}
+
+fn panic_message(stderr: &str) -> String {
+    // "thread 'main' panicked at src/lib.rs:1:2:\n<message>"
+    let mut lines = stderr
+        .lines()
+        .skip_while(|line| !line.contains("panicked at"));
+    match (lines.next(), lines.next()) {
+        (Some(header), Some(message)) => {
+            let location = header
+                .split_once("panicked at ")
+                .map(|(_, location)| location.trim_end_matches(':'))
+                .unwrap_or_default();
+            format!("solver panicked at {location}: {message}")
+        }
+        _ => String::from("solver failed on this input"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_solve_path() {
+        assert_eq!(parse_path("/solve/2023/5/2"), Some((2023, 5, 2)));
+        assert_eq!(parse_path("/solve/2023/10/1/"), Some((2023, 10, 1)));
+        assert_eq!(parse_path("/solve/2023/x/1"), None);
+        assert_eq!(parse_path("/solve/5/1"), Some((2023, 5, 1)));
+        assert_eq!(parse_path("/solve/x/1"), None);
+        assert_eq!(parse_path("/solve/5"), None);
+        assert_eq!(parse_path("/solve/2023/5/1/extra"), None);
+        assert_eq!(parse_path("/run/2023/5/1"), None);
+    }
+
+    #[test]
+    fn extract_panic_message() {
+        let stderr = "\nthread 'main' panicked at day8/src/lib.rs:80:39:\ncalled `Option::unwrap()` on a `None` value\nnote: run with `RUST_BACKTRACE=1`";
+        assert_eq!(
+            panic_message(stderr),
+            "solver panicked at day8/src/lib.rs:80:39: called `Option::unwrap()` on a `None` value"
+        );
+        assert_eq!(panic_message(""), "solver failed on this input");
+    }
+}
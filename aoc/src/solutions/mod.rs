@@ -0,0 +1,103 @@
+pub mod year2023;
+
+pub type Solver = fn(&str) -> String;
+
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part_1: Solver,
+    pub part_2: Solver,
+    pub alternatives: &'static [Implementation],
+}
+
+// Another way of solving one part, checked against `part_1`/`part_2` by `aoc diff`.
+pub struct Implementation {
+    pub name: &'static str,
+    pub part: u8,
+    pub solver: Solver,
+}
+
+impl Solution {
+    // The default implementation first, named "default", then the alternatives for `part`.
+    pub fn implementations(&self, part: u8) -> Vec<(&'static str, Solver)> {
+        let default = match part {
+            1 => self.part_1,
+            2 => self.part_2,
+            _ => return vec![],
+        };
+        let mut implementations = vec![("default", default)];
+        implementations.extend(
+            self.alternatives
+                .iter()
+                .filter(|i| i.part == part)
+                .map(|i| (i.name, i.solver)),
+        );
+        implementations
+    }
+}
+
+const YEARS: [&[Solution]; 1] = [&year2023::SOLUTIONS];
+
+pub fn all() -> impl Iterator<Item = &'static Solution> {
+    YEARS.iter().flat_map(|solutions| solutions.iter())
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Solution> {
+    all().find(|s| s.year == year && s.day == day)
+}
+
+pub fn find_solver(year: u16, day: u8, part: u8) -> Option<Solver> {
+    let solution = find(year, day)?;
+    match part {
+        1 => Some(solution.part_1),
+        2 => Some(solution.part_2),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solutions_are_unique_per_year_and_day() {
+        let mut keys = all().map(|s| (s.year, s.day)).collect::<Vec<_>>();
+        let count = keys.len();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), count);
+    }
+
+    #[test]
+    fn find_by_year_and_day() {
+        let solver = find_solver(2023, 9, 2).unwrap();
+        assert_eq!(solver("10 13 16 21 30 45"), "5");
+        assert!(find_solver(2023, 9, 3).is_none());
+        assert!(find_solver(2015, 9, 1).is_none());
+    }
+
+    #[test]
+    fn alternatives_are_named_uniquely() {
+        for solution in all() {
+            for part in 1..=2 {
+                let mut names = solution
+                    .implementations(part)
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<_>>();
+                let count = names.len();
+                names.sort();
+                names.dedup();
+                assert_eq!(names.len(), count);
+            }
+            assert!(solution
+                .alternatives
+                .iter()
+                .all(|i| i.part == 1 || i.part == 2));
+        }
+        let names = find(2023, 10).unwrap().implementations(2);
+        assert_eq!(names[0].0, "default");
+        assert_eq!(names.len(), 3);
+        assert!(find(2023, 9).unwrap().implementations(3).is_empty());
+    }
+}
@@ -0,0 +1,111 @@
+use super::{Implementation, Solution};
+
+pub const SOLUTIONS: [Solution; 10] = [
+    Solution {
+        year: 2023,
+        day: 1,
+        part_1: |c| aoc2023_day1::calc_sum_part_1(c).to_string(),
+        part_2: |c| aoc2023_day1::calc_sum_part_2(c, &english()).to_string(),
+        alternatives: &[
+            Implementation {
+                name: "collect",
+                part: 1,
+                solver: |c| aoc2023_day1::calc_sum_part_1_collect(c).to_string(),
+            },
+            Implementation {
+                name: "word-scan",
+                part: 2,
+                solver: |c| aoc2023_day1::calc_sum_part_2_word_scan(c, &english()).to_string(),
+            },
+        ],
+    },
+    Solution {
+        year: 2023,
+        day: 2,
+        part_1: |c| aoc2023_day2::calc_part_1(c).to_string(),
+        part_2: |c| aoc2023_day2::calc_part_2(c).to_string(),
+        alternatives: &[],
+    },
+    Solution {
+        year: 2023,
+        day: 3,
+        part_1: |c| aoc2023_day3::calc(c).0.to_string(),
+        part_2: |c| aoc2023_day3::calc(c).1.to_string(),
+        alternatives: &[],
+    },
+    Solution {
+        year: 2023,
+        day: 4,
+        part_1: |c| aoc2023_day4::calc_winning_points(c).to_string(),
+        part_2: |c| aoc2023_day4::calc_part_2(c).to_string(),
+        alternatives: &[],
+    },
+    Solution {
+        year: 2023,
+        day: 5,
+        part_1: |c| aoc2023_day5::calc_part_1(c).to_string(),
+        part_2: |c| aoc2023_day5::calc_part_2(c).to_string(),
+        alternatives: &[],
+    },
+    Solution {
+        year: 2023,
+        day: 6,
+        part_1: |c| aoc2023_day6::calc_part_1(c).to_string(),
+        part_2: |c| aoc2023_day6::calc_part_2(c).to_string(),
+        alternatives: &[
+            Implementation {
+                name: "closed-form",
+                part: 1,
+                solver: |c| aoc2023_day6::calc_part_1_closed_form(c).to_string(),
+            },
+            Implementation {
+                name: "closed-form",
+                part: 2,
+                solver: |c| aoc2023_day6::calc_part_2_closed_form(c).to_string(),
+            },
+        ],
+    },
+    Solution {
+        year: 2023,
+        day: 7,
+        part_1: |c| aoc2023_day7::calc_part_1(c).to_string(),
+        part_2: |c| aoc2023_day7::calc_part_2(c).to_string(),
+        alternatives: &[],
+    },
+    Solution {
+        year: 2023,
+        day: 8,
+        part_1: |c| aoc2023_day8::calc_part_1(c).to_string(),
+        part_2: |c| aoc2023_day8::calc_part_2(c).to_string(),
+        alternatives: &[],
+    },
+    Solution {
+        year: 2023,
+        day: 9,
+        part_1: |c| aoc2023_day9::part_1::calc_part_1(c).to_string(),
+        part_2: |c| aoc2023_day9::part_2::calc_part_2(c).to_string(),
+        alternatives: &[],
+    },
+    Solution {
+        year: 2023,
+        day: 10,
+        part_1: |c| aoc2023_day10::calc_part_1(c).to_string(),
+        part_2: |c| aoc2023_day10::calc_part_2(c).to_string(),
+        alternatives: &[
+            Implementation {
+                name: "pick",
+                part: 2,
+                solver: |c| aoc2023_day10::calc_part_2_pick(c).to_string(),
+            },
+            Implementation {
+                name: "scanline",
+                part: 2,
+                solver: |c| aoc2023_day10::calc_part_2_scanline(c).to_string(),
+            },
+        ],
+    },
+];
+
+fn english() -> aoc2023_day1::vocabulary::Vocabulary {
+    aoc2023_day1::vocabulary::Vocabulary::english()
+}
@@ -0,0 +1,258 @@
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MAGIC: &[u8] = b"AOCENC1\n";
+const NONCE_LEN: usize = 12;
+
+pub const KEY_ENV: &str = "AOC_INPUT_KEY";
+pub const KEY_FILE_ENV: &str = "AOC_INPUT_KEY_FILE";
+
+pub struct Vault {
+    cipher: ChaCha20Poly1305,
+}
+
+impl Vault {
+    pub fn from_hex(hex_key: &str) -> Result<Vault, Box<dyn Error>> {
+        let bytes = hex::decode(hex_key.trim())
+            .map_err(|_| "input key must be 64 hexadecimal characters")?;
+        if bytes.len() != 32 {
+            return Err("input key must be 64 hexadecimal characters".into());
+        }
+        Ok(Vault {
+            cipher: ChaCha20Poly1305::new(Key::from_slice(&bytes)),
+        })
+    }
+
+    // The key comes from `AOC_INPUT_KEY`, then the file named by `AOC_INPUT_KEY_FILE`, then
+    // `<root>/.aoc-key`.
+    pub fn from_env(root: &Path) -> Result<Option<Vault>, Box<dyn Error>> {
+        if let Ok(key) = env::var(KEY_ENV) {
+            return Vault::from_hex(&key).map(Some);
+        }
+        let key_file = match env::var(KEY_FILE_ENV) {
+            Ok(path) => PathBuf::from(path),
+            Err(_) if root.join(".aoc-key").exists() => root.join(".aoc-key"),
+            Err(_) => return Ok(None),
+        };
+        let key = fs::read_to_string(&key_file)
+            .map_err(|err| format!("can't read key file {}: {err}", key_file.display()))?;
+        Vault::from_hex(&key).map(Some)
+    }
+
+    pub fn generate_key() -> String {
+        hex::encode(ChaCha20Poly1305::generate_key(&mut OsRng))
+    }
+
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let payload = Payload {
+            msg: plaintext,
+            aad: MAGIC,
+        };
+        let ciphertext = self.cipher.encrypt(&nonce, payload).unwrap();
+        [MAGIC, nonce.as_slice(), &ciphertext].concat()
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, &'static str> {
+        let rest = data
+            .strip_prefix(MAGIC)
+            .ok_or("not an encrypted input file")?;
+        if rest.len() < NONCE_LEN {
+            return Err("encrypted input file is truncated");
+        }
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        let payload = Payload {
+            msg: ciphertext,
+            aad: MAGIC,
+        };
+        self.cipher
+            .decrypt(Nonce::from_slice(nonce), payload)
+            .map_err(|_| "wrong key or corrupted file")
+    }
+}
+
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".enc");
+    PathBuf::from(name)
+}
+
+// Reads `path`, or decrypts `path.enc` when only the encrypted copy is there.
+pub fn read_input(path: &Path, vault: Option<&Vault>) -> Result<String, Box<dyn Error>> {
+    if path.exists() {
+        return Ok(fs::read_to_string(path)?);
+    }
+
+    let encrypted = encrypted_path(path);
+    let data =
+        fs::read(&encrypted).map_err(|err| format!("can't read {}: {err}", path.display()))?;
+    let vault = vault.ok_or(format!(
+        "{} is encrypted; set {KEY_ENV} or {KEY_FILE_ENV}",
+        encrypted.display()
+    ))?;
+    let plaintext = vault
+        .decrypt(&data)
+        .map_err(|err| format!("can't decrypt {}: {err}", encrypted.display()))?;
+    Ok(String::from_utf8(plaintext)?)
+}
+
+fn files_with_extension(dir: &Path, extension: &str) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut files = vec![];
+    if !dir.exists() {
+        return Ok(files);
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(files_with_extension(&path, extension)?);
+        } else if path.extension().is_some_and(|ext| ext == extension) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+// Encrypts every `*.txt` under `dir` to `*.txt.enc` and removes the plaintext. An existing
+// `*.txt.enc` that already decrypts to the same input is kept as it is, so locking again after
+// `unlock` only rewrites the inputs that changed; those are the ones returned.
+pub fn lock(dir: &Path, vault: &Vault) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut locked = vec![];
+    for path in files_with_extension(dir, "txt")? {
+        let encrypted = encrypted_path(&path);
+        let plaintext = fs::read(&path)?;
+        let unchanged = fs::read(&encrypted)
+            .ok()
+            .and_then(|data| vault.decrypt(&data).ok())
+            .is_some_and(|existing| existing == plaintext);
+        if !unchanged {
+            fs::write(&encrypted, vault.encrypt(&plaintext))?;
+            locked.push(encrypted);
+        }
+        fs::remove_file(&path)?;
+    }
+    Ok(locked)
+}
+
+// Decrypts every `*.txt.enc` under `dir` next to it, keeping the encrypted copy.
+pub fn unlock(dir: &Path, vault: &Vault) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut unlocked = vec![];
+    for encrypted in files_with_extension(dir, "enc")? {
+        let plaintext = vault
+            .decrypt(&fs::read(&encrypted)?)
+            .map_err(|err| format!("can't decrypt {}: {err}", encrypted.display()))?;
+        let path = encrypted.with_extension("");
+        fs::write(&path, plaintext)?;
+        unlocked.push(path);
+    }
+    Ok(unlocked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+    const OTHER_KEY: &str = "ff0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn round_trip() {
+        let vault = Vault::from_hex(KEY).unwrap();
+        let encrypted = vault.encrypt(b"seeds: 79 14 55 13");
+        assert!(encrypted.starts_with(MAGIC));
+        assert_ne!(encrypted, vault.encrypt(b"seeds: 79 14 55 13"));
+        assert_eq!(vault.decrypt(&encrypted).unwrap(), b"seeds: 79 14 55 13");
+    }
+
+    #[test]
+    fn wrong_key_or_tampering() {
+        let vault = Vault::from_hex(KEY).unwrap();
+        let mut encrypted = vault.encrypt(b"0 3 6 9 12 15");
+
+        let other = Vault::from_hex(OTHER_KEY).unwrap();
+        assert_eq!(
+            other.decrypt(&encrypted),
+            Err("wrong key or corrupted file")
+        );
+
+        let last = encrypted.len() - 1;
+        encrypted[last] ^= 1;
+        assert_eq!(
+            vault.decrypt(&encrypted),
+            Err("wrong key or corrupted file")
+        );
+        assert_eq!(vault.decrypt(b"0 3 6"), Err("not an encrypted input file"));
+        assert_eq!(
+            vault.decrypt(MAGIC),
+            Err("encrypted input file is truncated")
+        );
+    }
+
+    #[test]
+    fn reject_malformed_keys() {
+        assert!(Vault::from_hex("not hex").is_err());
+        assert!(Vault::from_hex("0001").is_err());
+        assert_eq!(Vault::generate_key().len(), 64);
+        assert!(Vault::from_hex(&Vault::generate_key()).is_ok());
+    }
+
+    #[test]
+    fn lock_then_read_then_unlock() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join("inputs/2023");
+        fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("day09.txt");
+        fs::write(&input, "0 3 6 9 12 15").unwrap();
+        let vault = Vault::from_hex(KEY).unwrap();
+
+        let locked = lock(&root.path().join("inputs"), &vault).unwrap();
+        assert_eq!(locked, vec![dir.join("day09.txt.enc")]);
+        assert!(!input.exists());
+
+        assert_eq!(read_input(&input, Some(&vault)).unwrap(), "0 3 6 9 12 15");
+        let err = read_input(&input, None).unwrap_err().to_string();
+        assert!(
+            err.ends_with("day09.txt.enc is encrypted; set AOC_INPUT_KEY or AOC_INPUT_KEY_FILE")
+        );
+        let other = Vault::from_hex(OTHER_KEY).unwrap();
+        let err = read_input(&input, Some(&other)).unwrap_err().to_string();
+        assert!(err.ends_with("day09.txt.enc: wrong key or corrupted file"));
+
+        assert!(unlock(&root.path().join("inputs"), &other).is_err());
+        let unlocked = unlock(&root.path().join("inputs"), &vault).unwrap();
+        assert_eq!(unlocked, vec![input.clone()]);
+        assert_eq!(fs::read_to_string(&input).unwrap(), "0 3 6 9 12 15");
+    }
+
+    #[test]
+    fn lock_again_only_rewrites_changed_inputs() {
+        let dir = tempfile::tempdir().unwrap();
+        let vault = Vault::from_hex(KEY).unwrap();
+        for (name, contents) in [("day09.txt", "0 3 6"), ("day10.txt", "S-7")] {
+            fs::write(dir.path().join(name), contents).unwrap();
+        }
+        lock(dir.path(), &vault).unwrap();
+        let day9 = dir.path().join("day09.txt.enc");
+        let before = fs::read(&day9).unwrap();
+
+        unlock(dir.path(), &vault).unwrap();
+        fs::write(dir.path().join("day10.txt"), "S-J").unwrap();
+        let locked = lock(dir.path(), &vault).unwrap();
+        assert_eq!(locked, vec![dir.path().join("day10.txt.enc")]);
+        assert_eq!(fs::read(&day9).unwrap(), before);
+        assert!(!dir.path().join("day09.txt").exists());
+        assert_eq!(
+            read_input(&dir.path().join("day10.txt"), Some(&vault)).unwrap(),
+            "S-J"
+        );
+
+        // An encrypted copy made with another key is replaced.
+        unlock(dir.path(), &vault).unwrap();
+        let other = Vault::from_hex(OTHER_KEY).unwrap();
+        assert_eq!(lock(dir.path(), &other).unwrap().len(), 2);
+    }
+}
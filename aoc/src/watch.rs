@@ -0,0 +1,274 @@
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::error::Error;
+use std::fmt::Write;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use crate::data;
+use crate::describe;
+use crate::server::{self, SolveError};
+use crate::vault::{self, Vault};
+
+pub struct Config {
+    pub year: u16,
+    pub day: u8,
+    pub data: PathBuf,
+    pub root: PathBuf,
+    pub timeout: Duration,
+    // Solves run in a child `aoc solve`, like the server's, so a panic or a runaway solver on a
+    // hand-crafted input is reported instead of ending the watch.
+    pub exe: PathBuf,
+}
+
+impl Config {
+    fn input(&self) -> PathBuf {
+        data::input_path(&self.data, self.year, self.day)
+    }
+
+    fn fixture_dir(&self) -> PathBuf {
+        describe::fixture_dir(&self.root, self.year, self.day)
+    }
+
+    // The day's puzzle input followed by its saved examples.
+    pub fn files(&self) -> Vec<PathBuf> {
+        let mut fixtures = fs::read_dir(self.fixture_dir())
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| Some(entry.ok()?.path()))
+                    .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        fixtures.sort();
+
+        let mut files = vec![self.input()];
+        files.extend(fixtures);
+        files
+    }
+
+    // The dirs holding the input and the examples. One that doesn't exist yet is stood in for
+    // by its nearest existing ancestor, so that creating it is noticed.
+    pub fn dirs(&self) -> Vec<PathBuf> {
+        let mut dirs = vec![];
+        for dir in [
+            self.input().parent().unwrap().to_owned(),
+            self.fixture_dir(),
+        ] {
+            let existing = dir
+                .ancestors()
+                .find(|dir| dir.is_dir())
+                .unwrap_or(Path::new("."))
+                .to_owned();
+            if !dirs.contains(&existing) {
+                dirs.push(existing);
+            }
+        }
+        dirs
+    }
+
+    // The files `files()` lists, plus the encrypted input and examples that were just removed.
+    fn is_watched(&self, path: &Path) -> bool {
+        let input = self.input();
+        path == input
+            || path == vault::encrypted_path(&input)
+            || (path.parent() == Some(&self.fixture_dir())
+                && path.extension().is_some_and(|ext| ext == "txt"))
+    }
+}
+
+pub fn report(config: &Config, vault: Option<&Vault>) -> String {
+    let input = config.input();
+    let answers = data::read_answers(&data::answer_path(&config.data, config.year, config.day))
+        .unwrap_or_default();
+
+    let mut out = String::new();
+    for path in config.files() {
+        let is_input = path == input;
+        writeln!(out, "{}", path.display()).unwrap();
+        let contents = if is_input {
+            vault::read_input(&path, vault)
+        } else {
+            fs::read_to_string(&path).map_err(|err| err.into())
+        };
+        let contents = match contents {
+            Ok(contents) => contents,
+            Err(err) => {
+                writeln!(out, "    {err}").unwrap();
+                continue;
+            }
+        };
+
+        for part in 1..=2 {
+            let line = match server::solve_in_child(
+                &config.exe,
+                config.year,
+                config.day,
+                part,
+                &contents,
+                config.timeout,
+            ) {
+                Ok(answer) => {
+                    // Known answers are for the puzzle input, not the examples.
+                    let expected = answers[part as usize - 1].as_ref().filter(|_| is_input);
+                    let check = match expected {
+                        Some(expected) if *expected == answer.answer => String::from(" ok"),
+                        Some(expected) => format!(" WRONG, expected {expected}"),
+                        None => String::new(),
+                    };
+                    format!("{} ({:?}){check}", answer.answer, answer.elapsed)
+                }
+                Err(SolveError::Timeout) => format!("timed out after {:?}", config.timeout),
+                Err(SolveError::Failed(reason)) => reason,
+            };
+            writeln!(out, "    part {part}: {line}").unwrap();
+        }
+    }
+    out
+}
+
+// Editors save by writing, renaming or truncating, so any change to the day's input or one of
+// its examples counts. Other files in the same dirs, like other days' inputs, don't.
+pub fn is_relevant(config: &Config, event: &Event) -> bool {
+    !matches!(event.kind, EventKind::Access(_))
+        && event.paths.iter().any(|path| config.is_watched(path))
+}
+
+pub fn watch(config: &Config) -> Result<(), Box<dyn Error>> {
+    // Events name paths under the watched dirs the way the OS resolves them.
+    let canonical =
+        |dir: &Path| fs::canonicalize(dir).map_err(|err| format!("{}: {err}", dir.display()));
+    let config = &Config {
+        data: canonical(&config.data)?,
+        root: canonical(&config.root)?,
+        exe: config.exe.clone(),
+        ..*config
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    let mut watched = vec![];
+    let vault = Vault::from_env(&config.data)?;
+
+    loop {
+        // A dir created since the last report replaces the ancestor that stood in for it.
+        let dirs = config.dirs();
+        for dir in watched.iter().filter(|dir| !dirs.contains(dir)) {
+            let _ = watcher.unwatch(dir);
+        }
+        for dir in dirs.iter().filter(|dir| !watched.contains(dir)) {
+            watcher.watch(dir, RecursiveMode::NonRecursive)?;
+        }
+        watched = dirs;
+
+        if io::stdout().is_terminal() {
+            print!("\x1b[2J\x1b[H");
+        }
+        println!("{} day {}", config.year, config.day);
+        print!("{}", report(config, vault.as_ref()));
+        println!("watching for changes, Ctrl-C to stop");
+
+        while !is_relevant(config, &rx.recv()??) && config.dirs() == watched {}
+        // Let the rest of a burst of events for the same save arrive.
+        while rx.recv_timeout(Duration::from_millis(100)).is_ok() {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify::event::{AccessKind, ModifyKind};
+
+    fn config(root: &Path) -> Config {
+        Config {
+            year: 2023,
+            day: 10,
+            data: root.join("data"),
+            root: root.to_owned(),
+            timeout: Duration::from_secs(1),
+            exe: PathBuf::from("aoc"),
+        }
+    }
+
+    #[test]
+    fn input_then_sorted_examples() {
+        let root = tempfile::tempdir().unwrap();
+        let config = config(root.path());
+        assert_eq!(config.dirs(), vec![root.path().to_owned()]);
+
+        let fixtures = root.path().join("2023/day10/fixtures");
+        fs::create_dir_all(&fixtures).unwrap();
+        fs::write(fixtures.join("example_2.txt"), "").unwrap();
+        fs::write(fixtures.join("example_1.txt"), "").unwrap();
+        fs::write(fixtures.join("notes.md"), "").unwrap();
+
+        assert_eq!(
+            config.files(),
+            vec![
+                root.path().join("data/inputs/2023/day10.txt"),
+                fixtures.join("example_1.txt"),
+                fixtures.join("example_2.txt"),
+            ]
+        );
+        assert_eq!(
+            config.dirs(),
+            vec![root.path().to_owned(), fixtures.clone()]
+        );
+
+        fs::create_dir_all(root.path().join("data/inputs/2023")).unwrap();
+        assert_eq!(
+            config.dirs(),
+            vec![root.path().join("data/inputs/2023"), fixtures]
+        );
+    }
+
+    #[test]
+    fn react_to_the_days_files_only() {
+        let config = config(Path::new("/aoc"));
+        let event = |kind, path: &str| Event::new(kind).add_path(PathBuf::from(path));
+        assert!(is_relevant(
+            &config,
+            &event(
+                EventKind::Modify(ModifyKind::Any),
+                "/aoc/data/inputs/2023/day10.txt"
+            )
+        ));
+        assert!(is_relevant(
+            &config,
+            &event(
+                EventKind::Remove(notify::event::RemoveKind::File),
+                "/aoc/data/inputs/2023/day10.txt.enc"
+            )
+        ));
+        assert!(is_relevant(
+            &config,
+            &event(
+                EventKind::Create(notify::event::CreateKind::File),
+                "/aoc/2023/day10/fixtures/example_3.txt"
+            )
+        ));
+        assert!(!is_relevant(
+            &config,
+            &event(
+                EventKind::Access(AccessKind::Any),
+                "/aoc/data/inputs/2023/day10.txt"
+            )
+        ));
+        assert!(!is_relevant(
+            &config,
+            &event(
+                EventKind::Modify(ModifyKind::Any),
+                "/aoc/data/inputs/2023/day09.txt"
+            )
+        ));
+        assert!(!is_relevant(
+            &config,
+            &event(
+                EventKind::Modify(ModifyKind::Any),
+                "/aoc/2023/day10/fixtures/.example_1.txt.swp"
+            )
+        ));
+    }
+}
@@ -0,0 +1,91 @@
+use std::env::consts::{DLL_EXTENSION, DLL_PREFIX};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use aoc::plugins::{self, Plugin};
+
+const DAY7: &str = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483";
+
+// Builds plugins/day7_sort_key and copies the library into `dir`.
+fn build_sample_plugin(dir: &Path) -> PathBuf {
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("plugins");
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--quiet", "--manifest-path"])
+        .arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("../plugins/day7_sort_key/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let name = format!("{DLL_PREFIX}day7_sort_key.{DLL_EXTENSION}");
+    let plugin = dir.join(&name);
+    fs::create_dir_all(dir).unwrap();
+    fs::copy(target_dir.join("debug").join(&name), &plugin).unwrap();
+    plugin
+}
+
+#[test]
+fn load_and_run_sample_plugin() {
+    let root = tempfile::tempdir().unwrap();
+    let dir = root.path().join("plugins");
+    let path = build_sample_plugin(&dir);
+    fs::write(dir.join(format!("broken.{DLL_EXTENSION}")), "not a library").unwrap();
+
+    let (plugins, failures) = plugins::load_dir(&dir);
+    assert_eq!(failures.len(), 1);
+    assert!(failures[0]
+        .path
+        .ends_with(format!("broken.{DLL_EXTENSION}")));
+    assert_eq!(plugins.len(), 1);
+
+    let plugin = &plugins[0];
+    assert_eq!(plugin.path, path);
+    let solutions = plugin.find(2023, 7).collect::<Vec<_>>();
+    assert_eq!(solutions.len(), 2);
+    assert_eq!(
+        (solutions[0].part, solutions[0].name.as_str()),
+        (1, "sort-key")
+    );
+    assert_eq!(plugin.solve(solutions[0], DAY7), Ok(String::from("6440")));
+    assert_eq!(plugin.solve(solutions[1], DAY7), Ok(String::from("5905")));
+    assert!(plugin.find(2023, 8).next().is_none());
+
+    let err = plugin.solve(solutions[0], "32T3K").unwrap_err();
+    assert!(err.starts_with("solver panicked"));
+    assert!(Plugin::load(&dir.join("missing.so")).is_err());
+}
+
+#[test]
+fn run_reports_plugin_answers_next_to_built_in_ones() {
+    let root = tempfile::tempdir().unwrap();
+    build_sample_plugin(&root.path().join("plugins"));
+    let input = root.path().join("inputs/2023/day07.txt");
+    fs::create_dir_all(input.parent().unwrap()).unwrap();
+    fs::write(&input, DAY7).unwrap();
+    fs::create_dir_all(root.path().join("answers/2023")).unwrap();
+    fs::write(root.path().join("answers/2023/day07.txt"), "6440\n5905\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "2023", "7", "--no-cache", "--data"])
+        .arg(root.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines = stdout.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 5);
+    assert!(lines[1].starts_with("part 1: 6440 ("));
+    assert!(lines[3].starts_with("part 1 [sort-key]: 6440 ("));
+    assert!(lines[3].ends_with(") ok"));
+    assert!(lines[4].starts_with("part 2 [sort-key]: 5905 ("));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["plugins", "--data"])
+        .arg(root.path())
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("    2023 day 7 part 2: sort-key\n"));
+}
@@ -0,0 +1,134 @@
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::server::{self, Config, SolveError};
+
+const DAY9: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+
+// Every step leads back to AAA, so day8 part 1 never reaches ZZZ.
+const DAY8_LOOP: &str = "L\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)";
+
+fn start_server() -> SocketAddr {
+    let config = Config {
+        addr: String::from("127.0.0.1:0"),
+        max_body: 4096,
+        timeout: Duration::from_secs(1),
+        workers: 4,
+        exe: PathBuf::from(env!("CARGO_BIN_EXE_aoc")),
+    };
+    let server = server::bind(&config).unwrap();
+    let addr = server.server_addr().to_ip().unwrap();
+    thread::spawn(move || server::serve(server, config));
+    addr
+}
+
+fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, serde_json::Value) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(
+        stream,
+        "{method} {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    )
+    .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let status = response[9..12].parse::<u16>().unwrap();
+    let body = response.split("\r\n\r\n").nth(1).unwrap();
+    (status, serde_json::from_str(body).unwrap())
+}
+
+#[test]
+fn solve_returns_answer_and_timing() {
+    let addr = start_server();
+
+    let (status, body) = request(addr, "POST", "/solve/2023/9/1", DAY9);
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], "114");
+    assert_eq!(body["year"], 2023);
+    assert_eq!(body["day"], 9);
+    assert_eq!(body["part"], 1);
+    assert!(body["elapsed_ms"].as_f64().unwrap() >= 0.0);
+
+    let (status, body) = request(addr, "POST", "/solve/2023/9/2", DAY9);
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], "2");
+
+    // Without a year, the day is one of 2023's.
+    let (status, body) = request(addr, "POST", "/solve/9/2", DAY9);
+    assert_eq!(status, 200);
+    assert_eq!(body["year"], 2023);
+    assert_eq!(body["answer"], "2");
+}
+
+#[test]
+fn reject_bad_requests() {
+    let addr = start_server();
+
+    assert_eq!(request(addr, "POST", "/solve/2023/26/1", DAY9).0, 404);
+    assert_eq!(request(addr, "POST", "/solve/2015/9/1", DAY9).0, 404);
+    assert_eq!(request(addr, "POST", "/solve/2023/9/3", DAY9).0, 404);
+    assert_eq!(request(addr, "POST", "/answers", DAY9).0, 404);
+    assert_eq!(request(addr, "GET", "/solve/2023/9/1", "").0, 405);
+    assert_eq!(
+        request(addr, "POST", "/solve/2023/9/1", &"1 ".repeat(4096)).0,
+        413
+    );
+}
+
+#[test]
+fn report_solver_panic() {
+    let addr = start_server();
+
+    let (status, body) = request(addr, "POST", "/solve/2023/8/1", "L\n\nAAA = (BBB, BBB)");
+    assert_eq!(status, 422);
+    assert!(body["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("solver panicked"));
+}
+
+#[test]
+fn time_out_runaway_solver_without_blocking_others() {
+    let addr = start_server();
+
+    let start = Instant::now();
+    let slow = thread::spawn(move || request(addr, "POST", "/solve/2023/8/1", DAY8_LOOP));
+    thread::sleep(Duration::from_millis(50));
+
+    let (status, body) = request(addr, "POST", "/solve/2023/9/1", DAY9);
+    assert_eq!(status, 200);
+    assert_eq!(body["answer"], "114");
+    assert!(start.elapsed() < Duration::from_secs(1));
+
+    let (status, _) = slow.join().unwrap();
+    assert_eq!(status, 504);
+}
+
+// A child that fills both pipes past their buffers before exiting must not be taken for a
+// runaway one.
+#[cfg(unix)]
+#[test]
+fn drain_large_output_while_waiting() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let exe = dir.path().join("noisy");
+    std::fs::write(
+        &exe,
+        "#!/bin/sh\ncat >/dev/null\nhead -c 1048576 /dev/zero >&2\necho 42\nhead -c 1048576 /dev/zero\nexit 1\n",
+    )
+    .unwrap();
+    std::fs::set_permissions(&exe, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let result = server::solve_in_child(&exe, 2023, 9, 1, DAY9, Duration::from_secs(5));
+    assert_eq!(
+        result,
+        Err(SolveError::Failed(String::from(
+            "solver failed on this input"
+        )))
+    );
+}
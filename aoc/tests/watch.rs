@@ -0,0 +1,119 @@
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+// Collects the lines of one report, up to the "watching for changes" line that ends it.
+fn next_report(lines: &Receiver<String>) -> Vec<String> {
+    let mut report = vec![];
+    loop {
+        let line = lines.recv_timeout(Duration::from_secs(20)).unwrap();
+        if line.starts_with("watching for changes") {
+            return report;
+        }
+        report.push(line);
+    }
+}
+
+#[test]
+fn re_solve_when_input_or_examples_change() {
+    let root = tempfile::tempdir().unwrap();
+    let input = root.path().join("inputs/2023/day09.txt");
+    fs::create_dir_all(input.parent().unwrap()).unwrap();
+    fs::write(&input, "0 3 6 9 12 15").unwrap();
+    fs::create_dir_all(root.path().join("answers/2023")).unwrap();
+    fs::write(root.path().join("answers/2023/day09.txt"), "18\n-3\n").unwrap();
+    let fixtures = root.path().join("2023/day9/fixtures");
+    fs::create_dir_all(&fixtures).unwrap();
+    fs::write(fixtures.join("example_1.txt"), "1 3 6 10 15 21").unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["watch", "--day", "9", "--data"])
+        .arg(root.path())
+        .arg("--root")
+        .arg(root.path())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let stdout = child.stdout.take().unwrap();
+    let (tx, lines) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines() {
+            if tx.send(line.unwrap()).is_err() {
+                break;
+            }
+        }
+    });
+
+    let report = next_report(&lines);
+    assert_eq!(report[0], "2023 day 9");
+    assert!(report[1].ends_with("day09.txt"));
+    assert!(report[2].starts_with("    part 1: 18 ("));
+    assert!(report[2].ends_with(") ok"));
+    assert!(report[3].starts_with("    part 2: -3 ("));
+    assert!(report[4].ends_with("example_1.txt"));
+    assert!(report[5].starts_with("    part 1: 28 ("));
+    assert!(!report[5].contains("ok"));
+
+    fs::write(&input, "10 13 16 21 30 45").unwrap();
+    let report = next_report(&lines);
+    assert!(report[2].starts_with("    part 1: 68 ("));
+    assert!(report[2].ends_with("WRONG, expected 18"));
+
+    fs::write(fixtures.join("example_1.txt"), "1 3 x").unwrap();
+    let report = next_report(&lines);
+    assert!(report[5].starts_with("    part 1: solver panicked at "));
+    assert!(report[5].contains("ParseIntError"));
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
+
+#[test]
+fn ignore_other_days_and_pick_up_a_new_fixtures_dir() {
+    let root = tempfile::tempdir().unwrap();
+    let input = root.path().join("inputs/2023/day09.txt");
+    fs::create_dir_all(input.parent().unwrap()).unwrap();
+    fs::write(&input, "0 3 6 9 12 15").unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["watch", "--day", "9", "--data"])
+        .arg(root.path())
+        .arg("--root")
+        .arg(root.path())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let stdout = child.stdout.take().unwrap();
+    let (tx, lines) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines() {
+            if tx.send(line.unwrap()).is_err() {
+                break;
+            }
+        }
+    });
+
+    let report = next_report(&lines);
+    assert_eq!(report.len(), 4);
+
+    // Another day's input is in the same dir, but only the change after it re-solves.
+    fs::write(root.path().join("inputs/2023/day08.txt"), "L").unwrap();
+    thread::sleep(Duration::from_millis(300));
+    fs::write(&input, "10 13 16 21 30 45").unwrap();
+    let report = next_report(&lines);
+    assert!(report[2].starts_with("    part 1: 68 ("));
+
+    let fixtures = root.path().join("2023/day9/fixtures");
+    fs::create_dir_all(&fixtures).unwrap();
+    next_report(&lines);
+    fs::write(fixtures.join("example_1.txt"), "1 3 6 10 15 21").unwrap();
+    let report = next_report(&lines);
+    assert!(report[4].ends_with("example_1.txt"));
+    assert!(report[5].starts_with("    part 1: 28 ("));
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
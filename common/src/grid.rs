@@ -0,0 +1,19 @@
+pub fn parse_grid(contents: &str) -> Vec<Vec<char>> {
+    contents
+        .lines()
+        .map(|line| line.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_then_columns() {
+        let grid = parse_grid("..F\n.S|");
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid[1][1], 'S');
+        assert_eq!(grid[0][2], 'F');
+    }
+}
@@ -0,0 +1,49 @@
+// Divides before multiplying so it only overflows when the lcm itself doesn't fit in u64.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    a / gcd(a, b) * b
+}
+
+// gcd(n, 0) is n, and gcd(0, 0) is 0.
+pub fn gcd(a: u64, b: u64) -> u64 {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn greatest_common_divisor() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(18, 12), 6);
+        assert_eq!(gcd(7, 13), 1);
+        assert_eq!(gcd(12, 0), 12);
+        assert_eq!(gcd(0, 12), 12);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(4_294_967_279, 4_294_967_291), 1);
+        assert_eq!(gcd(u64::MAX, u64::MAX - 1), 1);
+    }
+
+    #[test]
+    fn least_common_multiple() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(1, 19), 19);
+        assert_eq!([2, 3, 4].iter().fold(1, |x, y| lcm(x, *y)), 12);
+        assert_eq!(lcm(0, 5), 0);
+        assert_eq!(lcm(5, 0), 0);
+        // Primes just below 2^32, whose product still fits in u64.
+        assert_eq!(
+            lcm(4_294_967_279, 4_294_967_291),
+            4_294_967_279 * 4_294_967_291
+        );
+        // a * b overflows, but the lcm is a itself.
+        assert_eq!(lcm(u64::MAX - 1, (u64::MAX - 1) / 2), u64::MAX - 1);
+    }
+}
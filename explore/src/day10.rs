@@ -0,0 +1,215 @@
+use day10::Point;
+
+use crate::{Puzzle, Tile};
+
+// Replays day10: first the loop is traced from `S`, then every other tile is classified with
+// `is_tile_inside`, in row order.
+pub struct PipeMaze {
+    map: Vec<Vec<char>>,
+    loop_len: usize,
+    loop_index: Vec<Vec<Option<usize>>>,
+    classified: Vec<Vec<Option<(usize, bool)>>>,
+    inside_before: Vec<usize>,
+}
+
+impl PipeMaze {
+    // The map must be a non-empty rectangle with a starting `S`.
+    pub fn new(contents: &str) -> Result<PipeMaze, String> {
+        let map = day10::parse_input(contents);
+        if map.is_empty() || map[0].is_empty() {
+            return Err("the map is empty".to_string());
+        }
+        if let Some(x) = map.iter().position(|row| row.len() != map[0].len()) {
+            return Err(format!(
+                "row {x} has {} tiles, but row 0 has {}",
+                map[x].len(),
+                map[0].len()
+            ));
+        }
+        if !map.iter().flatten().any(|&c| c == 'S') {
+            return Err("the map has no starting 'S'".to_string());
+        }
+        let boundary = day10::find_boundary(&map);
+        // The boundary ends back on `S`.
+        let loop_len = boundary.len() - 1;
+
+        let mut loop_index = vec![vec![None; map[0].len()]; map.len()];
+        for (i, point) in boundary[..loop_len].iter().enumerate() {
+            loop_index[point.x][point.y] = Some(i);
+        }
+
+        let mut classified = vec![vec![None; map[0].len()]; map.len()];
+        let mut inside_before = vec![0];
+        for x in 0..map.len() {
+            for y in 0..map[x].len() {
+                if loop_index[x][y].is_some() {
+                    continue;
+                }
+                let inside = day10::is_tile_inside(&Point { x, y }, &boundary);
+                classified[x][y] = Some((inside_before.len() - 1, inside));
+                inside_before.push(inside_before.last().unwrap() + inside as usize);
+            }
+        }
+
+        Ok(PipeMaze {
+            map,
+            loop_len,
+            loop_index,
+            classified,
+            inside_before,
+        })
+    }
+}
+
+impl Puzzle for PipeMaze {
+    fn title(&self) -> String {
+        String::from("day 10: pipe maze")
+    }
+
+    fn rows(&self) -> usize {
+        self.map.len()
+    }
+
+    fn cols(&self) -> usize {
+        self.map[0].len()
+    }
+
+    fn glyph(&self, row: usize, col: usize) -> char {
+        match self.map[row][col] {
+            '|' => '│',
+            '-' => '─',
+            'L' => '└',
+            'J' => '┘',
+            '7' => '┐',
+            'F' => '┌',
+            c => c,
+        }
+    }
+
+    fn steps(&self) -> usize {
+        self.loop_len + self.inside_before.len() - 1
+    }
+
+    fn tile(&self, row: usize, col: usize, step: usize) -> Tile {
+        if let Some(i) = self.loop_index[row][col] {
+            return if i + 1 == step && step <= self.loop_len {
+                Tile::Active
+            } else if i < step {
+                Tile::Highlight
+            } else {
+                Tile::Plain
+            };
+        }
+        match self.classified[row][col] {
+            Some((k, inside)) if self.loop_len + k < step => {
+                if inside {
+                    Tile::Inside
+                } else {
+                    Tile::Outside
+                }
+            }
+            _ => Tile::Plain,
+        }
+    }
+
+    fn describe(&self, row: usize, col: usize, step: usize) -> String {
+        let at = format!("'{}' at row {row}, col {col}", self.map[row][col]);
+        if let Some(i) = self.loop_index[row][col] {
+            return if i < step {
+                format!("{at}: loop tile {} of {}", i + 1, self.loop_len)
+            } else {
+                format!("{at}: on the loop, not traced yet")
+            };
+        }
+        match self.classified[row][col] {
+            Some((k, true)) if self.loop_len + k < step => format!("{at}: inside the loop"),
+            Some((k, false)) if self.loop_len + k < step => format!("{at}: outside the loop"),
+            _ => format!("{at}: not classified yet"),
+        }
+    }
+
+    fn status(&self, step: usize) -> String {
+        if step <= self.loop_len {
+            return format!(
+                "tracing the loop: {step} of {} tiles, farthest point {} steps away",
+                self.loop_len,
+                self.loop_len / 2
+            );
+        }
+        let done = step - self.loop_len;
+        format!(
+            "classified {done} of {} tiles: {} inside",
+            self.inside_before.len() - 1,
+            self.inside_before[done]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{draw, line};
+    use crate::App;
+    use ratatui::crossterm::event::KeyCode;
+    use ratatui::style::Color;
+
+    const EXAMPLE: &str = "...........\n.S-------7.\n.|F-----7|.\n.||.....||.\n.||.....||.\n.|L-7.F-J|.\n.|..|.|..|.\n.L--J.L--J.\n...........";
+
+    #[test]
+    fn trace_then_classify() {
+        let maze = PipeMaze::new(EXAMPLE).unwrap();
+        assert_eq!(maze.loop_len, 46);
+        assert_eq!(maze.steps(), 46 + 99 - 46);
+
+        assert_eq!(maze.tile(1, 1, 1), Tile::Active);
+        assert_eq!(maze.tile(1, 2, 1), Tile::Plain);
+        assert_eq!(maze.tile(1, 1, 2), Tile::Highlight);
+        assert_eq!(maze.tile(6, 2, 46), Tile::Plain);
+        assert_eq!(maze.tile(6, 2, maze.steps()), Tile::Inside);
+        assert_eq!(maze.tile(3, 3, maze.steps()), Tile::Outside);
+
+        assert_eq!(
+            maze.status(10),
+            "tracing the loop: 10 of 46 tiles, farthest point 23 steps away"
+        );
+        assert_eq!(
+            maze.status(maze.steps()),
+            "classified 53 of 53 tiles: 4 inside"
+        );
+        assert_eq!(
+            maze.describe(6, 2, maze.steps()),
+            "'.' at row 6, col 2: inside the loop"
+        );
+        assert_eq!(
+            maze.describe(1, 1, 0),
+            "'S' at row 1, col 1: on the loop, not traced yet"
+        );
+    }
+
+    #[test]
+    fn reject_invalid_maps() {
+        let error = |contents| PipeMaze::new(contents).err().unwrap();
+        assert_eq!(error(""), "the map is empty");
+        assert_eq!(error("S-7\n|.|\nL-"), "row 2 has 2 tiles, but row 0 has 3");
+        assert_eq!(error("F-7\n|.|\nL-J"), "the map has no starting 'S'");
+    }
+
+    #[test]
+    fn render_loop_with_box_drawing() {
+        let mut app = App::new(Box::new(PipeMaze::new(EXAMPLE).unwrap()));
+        app.handle_key(KeyCode::Char('G'));
+        for _ in 0..6 {
+            app.handle_key(KeyCode::Down);
+        }
+        app.handle_key(KeyCode::Right);
+        app.handle_key(KeyCode::Right);
+        let buffer = draw(&mut app, 60, 14);
+
+        assert_eq!(line(&buffer, 2), format!("│.S───────┐.{}│", " ".repeat(47)));
+        assert!(line(&buffer, 7).starts_with("│.│..│.│..│."));
+        assert_eq!(buffer[(3, 7)].fg, Color::Green);
+        assert_eq!(buffer[(2, 2)].fg, Color::Yellow);
+        assert!(line(&buffer, 11).starts_with("'.' at row 6, col 2: inside the loop"));
+        assert!(line(&buffer, 12).starts_with("classified 53 of 53 tiles: 4 inside"));
+    }
+}
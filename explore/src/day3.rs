@@ -0,0 +1,247 @@
+use day3::{Number, Symbol};
+use std::collections::HashMap;
+
+use crate::{Puzzle, Tile};
+
+// Replays day3 one symbol at a time: each step highlights the numbers touching that symbol.
+pub struct Schematic {
+    map: Vec<Vec<char>>,
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    adjacent: Vec<Vec<usize>>,
+    first_step: Vec<Option<usize>>,
+    number_at: HashMap<(usize, usize), usize>,
+    symbol_at: HashMap<(usize, usize), usize>,
+}
+
+impl Schematic {
+    pub fn new(contents: &str) -> Schematic {
+        let map = contents.lines().map(|l| l.chars().collect()).collect();
+        let (symbols_coors, numbers_coors) = day3::parse_input(contents);
+
+        let mut numbers = numbers_coors
+            .values()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>();
+        numbers.sort_by_key(|n| n.start_coor);
+        let mut symbols = symbols_coors.into_values().flatten().collect::<Vec<_>>();
+        symbols.sort_by_key(|s| s.coor);
+
+        let index_of = numbers
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.start_coor, i))
+            .collect::<HashMap<_, _>>();
+        let adjacent = symbols
+            .iter()
+            .map(|s| {
+                day3::adjacent_numbers(s, &numbers_coors)
+                    .iter()
+                    .map(|n| index_of[&n.start_coor])
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut first_step = vec![None; numbers.len()];
+        for (i, touching) in adjacent.iter().enumerate() {
+            for n in touching {
+                first_step[*n] = first_step[*n].or(Some(i + 1));
+            }
+        }
+
+        let number_at = numbers
+            .iter()
+            .enumerate()
+            .flat_map(|(i, n)| {
+                (n.start_coor.1..n.end_coor.1).map(move |col| ((n.start_coor.0, col), i))
+            })
+            .collect();
+        let symbol_at = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.coor, i))
+            .collect();
+
+        Schematic {
+            map,
+            numbers,
+            symbols,
+            adjacent,
+            first_step,
+            number_at,
+            symbol_at,
+        }
+    }
+
+    // The same sums as `day3::calc`, over the symbols scanned so far.
+    fn sums(&self, step: usize) -> (u32, u32) {
+        let mut part_sum = 0;
+        let mut gear_sum = 0;
+        for (symbol, touching) in self.symbols.iter().zip(&self.adjacent).take(step) {
+            part_sum += touching.iter().map(|n| self.numbers[*n].value).sum::<u32>();
+            if symbol.value == "*" && touching.len() >= 2 {
+                gear_sum += self.numbers[touching[0]].value * self.numbers[touching[1]].value;
+            }
+        }
+        (part_sum, gear_sum)
+    }
+
+    fn values(&self, touching: &[usize]) -> String {
+        touching
+            .iter()
+            .map(|n| self.numbers[*n].value.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl Puzzle for Schematic {
+    fn title(&self) -> String {
+        String::from("day 3: gear ratios")
+    }
+
+    fn rows(&self) -> usize {
+        self.map.len()
+    }
+
+    fn cols(&self) -> usize {
+        self.map.iter().map(|row| row.len()).max().unwrap_or(0)
+    }
+
+    fn glyph(&self, row: usize, col: usize) -> char {
+        self.map
+            .get(row)
+            .and_then(|line| line.get(col))
+            .copied()
+            .unwrap_or(' ')
+    }
+
+    fn steps(&self) -> usize {
+        self.symbols.len()
+    }
+
+    fn tile(&self, row: usize, col: usize, step: usize) -> Tile {
+        if let Some(s) = self.symbol_at.get(&(row, col)) {
+            return match step.cmp(&(s + 1)) {
+                std::cmp::Ordering::Equal => Tile::Active,
+                std::cmp::Ordering::Greater => Tile::Highlight,
+                std::cmp::Ordering::Less => Tile::Plain,
+            };
+        }
+        let Some(n) = self.number_at.get(&(row, col)) else {
+            return Tile::Plain;
+        };
+        if step > 0 && self.adjacent[step - 1].contains(n) {
+            Tile::Active
+        } else if self.first_step[*n].is_some_and(|first| first <= step) {
+            Tile::Highlight
+        } else {
+            Tile::Plain
+        }
+    }
+
+    fn describe(&self, row: usize, col: usize, step: usize) -> String {
+        let at = format!("'{}' at row {row}, col {col}", self.glyph(row, col));
+        if let Some(s) = self.symbol_at.get(&(row, col)) {
+            return format!(
+                "{at}: symbol {} of {}, touches [{}]",
+                s + 1,
+                self.symbols.len(),
+                self.values(&self.adjacent[*s])
+            );
+        }
+        let Some(n) = self.number_at.get(&(row, col)) else {
+            return at;
+        };
+        let value = self.numbers[*n].value;
+        match self.first_step[*n] {
+            Some(first) if first <= step => format!("{at}: {value} is a part number"),
+            Some(_) => format!("{at}: {value}, not reached yet"),
+            None => format!("{at}: {value} touches no symbol"),
+        }
+    }
+
+    fn status(&self, step: usize) -> String {
+        let (part_sum, gear_sum) = self.sums(step);
+        let sums = format!("part numbers {part_sum}, gear ratios {gear_sum}");
+        if step == 0 {
+            return format!("no symbols scanned yet | {sums}");
+        }
+        let symbol = &self.symbols[step - 1];
+        format!(
+            "'{}' at {:?} touches [{}] | {sums}",
+            symbol.value,
+            symbol.coor,
+            self.values(&self.adjacent[step - 1])
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{draw, line};
+    use crate::App;
+    use ratatui::style::{Color, Modifier};
+
+    const EXAMPLE: &str = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..";
+
+    #[test]
+    fn step_through_symbols() {
+        let schematic = Schematic::new(EXAMPLE);
+        assert_eq!(schematic.steps(), 6);
+        assert_eq!(schematic.tile(0, 0, 0), Tile::Plain);
+        assert_eq!(schematic.tile(1, 3, 1), Tile::Active);
+        assert_eq!(schematic.tile(0, 2, 1), Tile::Active);
+        assert_eq!(schematic.tile(0, 2, 2), Tile::Highlight);
+        assert_eq!(schematic.tile(0, 5, 6), Tile::Plain);
+        assert_eq!(
+            schematic.status(1),
+            "'*' at (1, 3) touches [467, 35] | part numbers 502, gear ratios 16345"
+        );
+        assert_eq!(
+            schematic.status(6),
+            "'*' at (8, 5) touches [755, 598] | part numbers 4361, gear ratios 467835"
+        );
+        assert_eq!(schematic.sums(6), day3::calc(EXAMPLE));
+        assert_eq!(
+            schematic.describe(0, 6, 6),
+            "'1' at row 0, col 6: 114 touches no symbol"
+        );
+        assert_eq!(
+            schematic.describe(1, 3, 0),
+            "'*' at row 1, col 3: symbol 1 of 6, touches [467, 35]"
+        );
+    }
+
+    #[test]
+    fn render_part_numbers() {
+        let mut app = App::new(Box::new(Schematic::new(EXAMPLE)));
+        app.handle_key(ratatui::crossterm::event::KeyCode::Char('G'));
+        let buffer = draw(&mut app, 40, 15);
+
+        assert_eq!(line(&buffer, 1), "│467..114..                            │");
+        assert_eq!(
+            line(&buffer, 12),
+            "'4' at row 0, col 0: 467 is a part numbe"
+        );
+        assert_eq!(buffer[(1, 1)].fg, Color::Yellow);
+        assert!(buffer[(1, 1)].modifier.contains(Modifier::REVERSED));
+        assert_eq!(buffer[(6, 1)].fg, Color::Reset);
+        assert_eq!(buffer[(6, 9)].fg, Color::Red);
+    }
+
+    #[test]
+    fn explore_empty_schematic() {
+        let mut app = App::new(Box::new(Schematic::new("")));
+        app.handle_key(ratatui::crossterm::event::KeyCode::Down);
+        app.handle_key(ratatui::crossterm::event::KeyCode::Right);
+        assert_eq!(app.cursor, (0, 0));
+        let buffer = draw(&mut app, 40, 6);
+        assert_eq!(
+            line(&buffer, 3),
+            format!("' ' at row 0, col 0{}", " ".repeat(21))
+        );
+    }
+}
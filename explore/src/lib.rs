@@ -0,0 +1,293 @@
+pub mod day10;
+pub mod day3;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::Frame;
+use std::io;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Tile {
+    Plain,
+    Highlight,
+    Active,
+    Inside,
+    Outside,
+}
+
+// A grid puzzle whose solver can be replayed one step at a time. Step 0 is before the solver
+// starts and `steps()` is when it has finished.
+pub trait Puzzle {
+    fn title(&self) -> String;
+    fn rows(&self) -> usize;
+    fn cols(&self) -> usize;
+    fn glyph(&self, row: usize, col: usize) -> char;
+    fn steps(&self) -> usize;
+    fn tile(&self, row: usize, col: usize, step: usize) -> Tile;
+    fn describe(&self, row: usize, col: usize, step: usize) -> String;
+    fn status(&self, step: usize) -> String;
+}
+
+const HELP: &str = "arrows/hjkl move  n/p step  [/] 100 steps  g/G start/end  t highlight  q quit";
+
+pub struct App {
+    puzzle: Box<dyn Puzzle>,
+    pub cursor: (usize, usize),
+    pub step: usize,
+    pub highlight: bool,
+    offset: (usize, usize),
+}
+
+impl App {
+    pub fn new(puzzle: Box<dyn Puzzle>) -> App {
+        App {
+            puzzle,
+            cursor: (0, 0),
+            step: 0,
+            highlight: true,
+            offset: (0, 0),
+        }
+    }
+
+    // Returns false when the user asked to quit.
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        let (row, col) = self.cursor;
+        let steps = self.puzzle.steps();
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Up | KeyCode::Char('k') => self.cursor.0 = row.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.cursor.0 = (row + 1).min(self.puzzle.rows().saturating_sub(1))
+            }
+            KeyCode::Left | KeyCode::Char('h') => self.cursor.1 = col.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.cursor.1 = (col + 1).min(self.puzzle.cols().saturating_sub(1))
+            }
+            KeyCode::Char('n') => self.step = (self.step + 1).min(steps),
+            KeyCode::Char('p') => self.step = self.step.saturating_sub(1),
+            KeyCode::Char(']') => self.step = (self.step + 100).min(steps),
+            KeyCode::Char('[') => self.step = self.step.saturating_sub(100),
+            KeyCode::Char('g') => self.step = 0,
+            KeyCode::Char('G') => self.step = steps,
+            KeyCode::Char('t') => self.highlight = !self.highlight,
+            _ => {}
+        }
+        true
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let [grid, status, help] = Layout::vertical([
+            Constraint::Min(3),
+            Constraint::Length(2),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let block = Block::bordered().title(format!(
+            " {} - step {}/{} ",
+            self.puzzle.title(),
+            self.step,
+            self.puzzle.steps()
+        ));
+        let inner = block.inner(grid);
+        self.scroll_to_cursor(inner);
+        frame.render_widget(Paragraph::new(self.grid_lines(inner)).block(block), grid);
+
+        let (row, col) = self.cursor;
+        let status_lines = vec![
+            Line::from(self.puzzle.describe(row, col, self.step)),
+            Line::from(self.puzzle.status(self.step)),
+        ];
+        frame.render_widget(Paragraph::new(status_lines), status);
+        frame.render_widget(
+            Paragraph::new(HELP).style(Style::new().fg(Color::DarkGray)),
+            help,
+        );
+    }
+
+    // Moves the viewport just enough to keep the cursor inside it.
+    fn scroll_to_cursor(&mut self, area: Rect) {
+        let (height, width) = (area.height.max(1) as usize, area.width.max(1) as usize);
+        let (row, col) = self.cursor;
+        if row < self.offset.0 {
+            self.offset.0 = row;
+        } else if row >= self.offset.0 + height {
+            self.offset.0 = row + 1 - height;
+        }
+        if col < self.offset.1 {
+            self.offset.1 = col;
+        } else if col >= self.offset.1 + width {
+            self.offset.1 = col + 1 - width;
+        }
+    }
+
+    fn grid_lines(&self, area: Rect) -> Vec<Line<'static>> {
+        let rows = self.offset.0..self.puzzle.rows().min(self.offset.0 + area.height as usize);
+        let cols = self.offset.1..self.puzzle.cols().min(self.offset.1 + area.width as usize);
+
+        rows.map(|row| {
+            cols.clone()
+                .map(|col| {
+                    let tile = if self.highlight {
+                        self.puzzle.tile(row, col, self.step)
+                    } else {
+                        Tile::Plain
+                    };
+                    let mut style = style(tile);
+                    if (row, col) == self.cursor {
+                        style = style.add_modifier(Modifier::REVERSED);
+                    }
+                    Span::styled(self.puzzle.glyph(row, col).to_string(), style)
+                })
+                .collect::<Line>()
+        })
+        .collect()
+    }
+}
+
+fn style(tile: Tile) -> Style {
+    match tile {
+        Tile::Plain => Style::new(),
+        Tile::Highlight => Style::new().fg(Color::Yellow),
+        Tile::Active => Style::new().fg(Color::Red).add_modifier(Modifier::BOLD),
+        Tile::Inside => Style::new().fg(Color::Green),
+        Tile::Outside => Style::new().fg(Color::Blue),
+    }
+}
+
+pub fn run(mut app: App) -> io::Result<()> {
+    let mut terminal = ratatui::try_init()?;
+    let result = (|| loop {
+        terminal.draw(|frame| app.render(frame))?;
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && !app.handle_key(key.code) {
+                return Ok(());
+            }
+        }
+    })();
+    ratatui::try_restore()?;
+    result
+}
+
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::buffer::Buffer;
+    use ratatui::Terminal;
+
+    pub fn draw(app: &mut App, width: u16, height: u16) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    pub fn line(buffer: &Buffer, y: u16) -> String {
+        (0..buffer.area.width)
+            .map(|x| buffer[(x, y)].symbol())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::testing::*;
+    use super::*;
+
+    struct Diagonal;
+
+    impl Puzzle for Diagonal {
+        fn title(&self) -> String {
+            String::from("diagonal")
+        }
+        fn rows(&self) -> usize {
+            20
+        }
+        fn cols(&self) -> usize {
+            30
+        }
+        fn glyph(&self, row: usize, col: usize) -> char {
+            if row == col {
+                '\\'
+            } else {
+                '.'
+            }
+        }
+        fn steps(&self) -> usize {
+            20
+        }
+        fn tile(&self, row: usize, col: usize, step: usize) -> Tile {
+            if row == col && row < step {
+                Tile::Highlight
+            } else {
+                Tile::Plain
+            }
+        }
+        fn describe(&self, row: usize, col: usize, _: usize) -> String {
+            format!("({row}, {col})")
+        }
+        fn status(&self, step: usize) -> String {
+            format!("{step} done")
+        }
+    }
+
+    #[test]
+    fn move_cursor_and_step_within_bounds() {
+        let mut app = App::new(Box::new(Diagonal));
+        assert!(app.handle_key(KeyCode::Up));
+        assert_eq!(app.cursor, (0, 0));
+        for _ in 0..25 {
+            app.handle_key(KeyCode::Char('j'));
+        }
+        app.handle_key(KeyCode::Right);
+        assert_eq!(app.cursor, (19, 1));
+
+        app.handle_key(KeyCode::Char('n'));
+        app.handle_key(KeyCode::Char('n'));
+        assert_eq!(app.step, 2);
+        app.handle_key(KeyCode::Char(']'));
+        assert_eq!(app.step, 20);
+        app.handle_key(KeyCode::Char('p'));
+        assert_eq!(app.step, 19);
+        app.handle_key(KeyCode::Char('g'));
+        assert_eq!(app.step, 0);
+        assert!(!app.handle_key(KeyCode::Char('q')));
+    }
+
+    #[test]
+    fn render_grid_status_and_cursor() {
+        let mut app = App::new(Box::new(Diagonal));
+        app.handle_key(KeyCode::Char('n'));
+        let buffer = draw(&mut app, 12, 8);
+
+        assert_eq!(line(&buffer, 0), "┌ diagonal ┐");
+        assert_eq!(line(&buffer, 1), "│\\.........│");
+        assert_eq!(line(&buffer, 2), "│.\\........│");
+        assert_eq!(line(&buffer, 5), "(0, 0)      ");
+        assert_eq!(line(&buffer, 6), "1 done      ");
+        assert_eq!(buffer[(1, 1)].fg, Color::Yellow);
+        assert!(buffer[(1, 1)].modifier.contains(Modifier::REVERSED));
+        assert_eq!(buffer[(2, 2)].fg, Color::Reset);
+
+        app.handle_key(KeyCode::Char('t'));
+        let buffer = draw(&mut app, 12, 8);
+        assert_eq!(buffer[(1, 1)].fg, Color::Reset);
+    }
+
+    #[test]
+    fn scroll_to_keep_cursor_visible() {
+        let mut app = App::new(Box::new(Diagonal));
+        for _ in 0..5 {
+            app.handle_key(KeyCode::Down);
+            app.handle_key(KeyCode::Right);
+        }
+        // The grid shows 2 rows and 10 columns, so row 5 is at the bottom.
+        let buffer = draw(&mut app, 12, 7);
+        assert_eq!(line(&buffer, 1), "│....\\.....│");
+        assert_eq!(line(&buffer, 2), "│.....\\....│");
+        assert!(buffer[(6, 2)].modifier.contains(Modifier::REVERSED));
+    }
+}
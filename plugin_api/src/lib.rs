@@ -0,0 +1,136 @@
+// The C ABI between the `aoc` runner and solution plugins. A plugin is a `cdylib` exporting:
+//
+//   uint32_t aoc_plugin_abi_version(void);
+//   size_t aoc_plugin_count(void);
+//   const SolutionInfo *aoc_plugin_info(size_t index);
+//   int32_t aoc_plugin_solve(size_t index, const uint8_t *input, size_t len,
+//                            WriteFn write, void *ctx);
+//
+// `aoc_plugin_solve` hands the answer (or the error message) to `write` instead of returning
+// memory, so nothing allocated on one side is freed on the other. Any change to these
+// signatures or to `SolutionInfo` must bump `ABI_VERSION`.
+use std::ffi::{c_char, c_void};
+use std::panic;
+
+pub const ABI_VERSION: u32 = 1;
+
+pub const SOLVED: i32 = 0;
+pub const FAILED: i32 = 1;
+pub const NO_SUCH_SOLUTION: i32 = 2;
+pub const INVALID_INPUT: i32 = 3;
+
+#[repr(C)]
+pub struct SolutionInfo {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    // NUL-terminated, static for the lifetime of the library.
+    pub name: *const c_char,
+}
+
+unsafe impl Sync for SolutionInfo {}
+
+pub type WriteFn = extern "C" fn(ctx: *mut c_void, ptr: *const u8, len: usize);
+
+pub type AbiVersionFn = unsafe extern "C" fn() -> u32;
+pub type CountFn = unsafe extern "C" fn() -> usize;
+pub type InfoFn = unsafe extern "C" fn(index: usize) -> *const SolutionInfo;
+pub type SolveFn = unsafe extern "C" fn(
+    index: usize,
+    input: *const u8,
+    len: usize,
+    write: WriteFn,
+    ctx: *mut c_void,
+) -> i32;
+
+/// Used by `export_plugin!`; runs `solver` on the input and reports the answer through `write`.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes.
+pub unsafe fn solve_with(
+    solver: fn(&str) -> String,
+    input: *const u8,
+    len: usize,
+    write: WriteFn,
+    ctx: *mut c_void,
+) -> i32 {
+    let bytes = std::slice::from_raw_parts(input, len);
+    let contents = match std::str::from_utf8(bytes) {
+        Ok(contents) => contents,
+        Err(_) => {
+            let message = "input is not valid UTF-8";
+            write(ctx, message.as_ptr(), message.len());
+            return INVALID_INPUT;
+        }
+    };
+
+    match panic::catch_unwind(|| solver(contents)) {
+        Ok(answer) => {
+            write(ctx, answer.as_ptr(), answer.len());
+            SOLVED
+        }
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| String::from("solver panicked"));
+            write(ctx, message.as_ptr(), message.len());
+            FAILED
+        }
+    }
+}
+
+// Exports the plugin ABI for a list of solutions:
+//
+//     plugin_api::export_plugin! {
+//         (2023, 7, 1, "sort-key") => part_1,
+//     }
+#[macro_export]
+macro_rules! export_plugin {
+    ($(($year:expr, $day:expr, $part:expr, $name:expr) => $solver:expr),* $(,)?) => {
+        static AOC_PLUGIN_INFOS: &[$crate::SolutionInfo] = &[
+            $($crate::SolutionInfo {
+                year: $year,
+                day: $day,
+                part: $part,
+                name: concat!($name, "\0").as_ptr() as *const ::std::ffi::c_char,
+            }),*
+        ];
+        static AOC_PLUGIN_SOLVERS: &[fn(&str) -> String] = &[$($solver),*];
+
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin_abi_version() -> u32 {
+            $crate::ABI_VERSION
+        }
+
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin_count() -> usize {
+            AOC_PLUGIN_INFOS.len()
+        }
+
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin_info(index: usize) -> *const $crate::SolutionInfo {
+            match AOC_PLUGIN_INFOS.get(index) {
+                Some(info) => info,
+                None => ::std::ptr::null(),
+            }
+        }
+
+        /// # Safety
+        /// `input` must point to `len` readable bytes.
+        #[no_mangle]
+        pub unsafe extern "C" fn aoc_plugin_solve(
+            index: usize,
+            input: *const u8,
+            len: usize,
+            write: $crate::WriteFn,
+            ctx: *mut ::std::ffi::c_void,
+        ) -> i32 {
+            match AOC_PLUGIN_SOLVERS.get(index) {
+                Some(solver) => $crate::solve_with(*solver, input, len, write, ctx),
+                None => $crate::NO_SUCH_SOLUTION,
+            }
+        }
+    };
+}
@@ -0,0 +1,80 @@
+// An alternative day 7 ranker: instead of comparing hands pairwise, every hand gets a sort key
+// of (kind, card values) where the kind is the sorted card counts, best count first.
+plugin_api::export_plugin! {
+    (2023, 7, 1, "sort-key") => part_1,
+    (2023, 7, 2, "sort-key") => part_2,
+}
+
+const ORDER_PART_1: &str = "23456789TJQKA";
+const ORDER_PART_2: &str = "J23456789TQKA";
+
+fn part_1(contents: &str) -> String {
+    total_winnings(contents, ORDER_PART_1, false).to_string()
+}
+
+fn part_2(contents: &str) -> String {
+    total_winnings(contents, ORDER_PART_2, true).to_string()
+}
+
+fn total_winnings(contents: &str, order: &str, jokers: bool) -> u64 {
+    let mut hands = contents
+        .lines()
+        .map(|line| {
+            let (cards, bid) = line.split_once(' ').unwrap();
+            (sort_key(cards, order, jokers), bid.parse::<u64>().unwrap())
+        })
+        .collect::<Vec<_>>();
+    hands.sort();
+
+    hands
+        .iter()
+        .enumerate()
+        .map(|(rank, (_, bid))| bid * (rank as u64 + 1))
+        .sum()
+}
+
+fn sort_key(cards: &str, order: &str, jokers: bool) -> (Vec<u8>, Vec<usize>) {
+    let values = cards
+        .chars()
+        .map(|c| order.find(c).unwrap())
+        .collect::<Vec<_>>();
+
+    let mut counts = [0u8; 13];
+    let mut joker_count = 0;
+    for c in cards.chars() {
+        if jokers && c == 'J' {
+            joker_count += 1;
+        } else {
+            counts[order.find(c).unwrap()] += 1;
+        }
+    }
+    let mut kind = counts.into_iter().filter(|n| *n > 0).collect::<Vec<_>>();
+    kind.sort_by(|a, b| b.cmp(a));
+    // Jokers always join the largest group.
+    match kind.first_mut() {
+        Some(largest) => *largest += joker_count,
+        None => kind.push(joker_count),
+    }
+
+    (kind, values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483";
+
+    #[test]
+    fn example() {
+        assert_eq!(part_1(EXAMPLE), "6440");
+        assert_eq!(part_2(EXAMPLE), "5905");
+    }
+
+    #[test]
+    fn jokers_join_the_largest_group() {
+        assert_eq!(sort_key("JJJJJ", ORDER_PART_2, true).0, vec![5]);
+        assert_eq!(sort_key("2233J", ORDER_PART_2, true).0, vec![3, 2]);
+        assert!(sort_key("KTJJT", ORDER_PART_2, true) > sort_key("QQQJA", ORDER_PART_2, true));
+    }
+}
@@ -0,0 +1,164 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+#[pyclass(frozen, eq, get_all, from_py_object)]
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Point {
+    x: usize,
+    y: usize,
+}
+
+#[pymethods]
+impl Point {
+    #[new]
+    fn new(x: usize, y: usize) -> Self {
+        Point { x, y }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Point(x={}, y={})", self.x, self.y)
+    }
+}
+
+impl From<day10::Point> for Point {
+    fn from(point: day10::Point) -> Self {
+        Point {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+impl From<&Point> for day10::Point {
+    fn from(point: &Point) -> Self {
+        day10::Point {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+#[pyclass(frozen, eq, get_all, skip_from_py_object)]
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Range {
+    begin: u64,
+    end: u64,
+}
+
+#[pymethods]
+impl Range {
+    // Half-open, so `end` may equal `begin` but not come before it.
+    #[new]
+    fn new(begin: u64, end: u64) -> PyResult<Self> {
+        if end < begin {
+            return Err(PyValueError::new_err(format!(
+                "Range end {end} is before its begin {begin}"
+            )));
+        }
+        Ok(Range { begin, end })
+    }
+
+    fn __len__(&self) -> usize {
+        (self.end - self.begin) as usize
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Range(begin={}, end={})", self.begin, self.end)
+    }
+}
+
+impl From<day5::Range> for Range {
+    fn from(range: day5::Range) -> Self {
+        Range {
+            begin: range.begin,
+            end: range.end,
+        }
+    }
+}
+
+#[pyfunction]
+fn solve_day4(contents: &str) -> (u64, u128) {
+    (
+        day4::calc_winning_points(contents),
+        day4::calc_part_2(contents),
+    )
+}
+
+#[pyfunction]
+fn number_of_matching(card: &str) -> usize {
+    day4::number_of_matching(card)
+}
+
+#[pyfunction]
+fn matching_counts(contents: &str) -> Vec<usize> {
+    contents.lines().map(day4::number_of_matching).collect()
+}
+
+#[pyfunction]
+fn solve_day5(contents: &str) -> (u64, u64) {
+    (day5::calc_part_1(contents), day5::calc_part_2(contents))
+}
+
+#[pyfunction]
+fn location_ranges(contents: &str) -> Vec<Range> {
+    day5::calc_location_ranges(contents)
+        .into_iter()
+        .map(Range::from)
+        .collect()
+}
+
+#[pyfunction]
+fn solve_day9(contents: &str) -> (i64, i64) {
+    (
+        day9::part_1::calc_part_1(contents),
+        day9::part_2::calc_part_2(contents),
+    )
+}
+
+#[pyfunction]
+fn next_value(history: Vec<i64>) -> i64 {
+    day9::part_1::next_value(&history)
+}
+
+#[pyfunction]
+fn previous_value(history: Vec<i64>) -> i64 {
+    day9::part_2::previous_value(&history)
+}
+
+#[pyfunction]
+fn solve_day10(contents: &str) -> (usize, usize) {
+    (day10::calc_part_1(contents), day10::calc_part_2(contents))
+}
+
+#[pyfunction]
+fn find_boundary(contents: &str) -> Vec<Point> {
+    let map = day10::parse_input(contents);
+    day10::find_boundary(&map)
+        .into_iter()
+        .map(Point::from)
+        .collect()
+}
+
+#[pyfunction]
+fn is_tile_inside(tile: &Point, boundary: Vec<Point>) -> bool {
+    let boundary = boundary.iter().map(day10::Point::from).collect::<Vec<_>>();
+    day10::is_tile_inside(&tile.into(), &boundary)
+}
+
+#[pymodule]
+pub fn pyaoc(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Point>()?;
+    m.add_class::<Range>()?;
+    m.add_function(wrap_pyfunction!(solve_day4, m)?)?;
+    m.add_function(wrap_pyfunction!(number_of_matching, m)?)?;
+    m.add_function(wrap_pyfunction!(matching_counts, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_day5, m)?)?;
+    m.add_function(wrap_pyfunction!(location_ranges, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_day9, m)?)?;
+    m.add_function(wrap_pyfunction!(next_value, m)?)?;
+    m.add_function(wrap_pyfunction!(previous_value, m)?)?;
+    m.add_function(wrap_pyfunction!(solve_day10, m)?)?;
+    m.add_function(wrap_pyfunction!(find_boundary, m)?)?;
+    m.add_function(wrap_pyfunction!(is_tile_inside, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::ffi::CString;
+use std::sync::Once;
+
+use pyaoc::pyaoc;
+
+static INIT: Once = Once::new();
+
+const DAY4: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+const DAY5: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+const DAY9: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+
+const DAY10: &str = "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...";
+
+fn run_python(script: &str) {
+    INIT.call_once(|| {
+        pyo3::append_to_inittab!(pyaoc);
+        Python::initialize();
+    });
+    Python::attach(|py| {
+        let globals = PyDict::new(py);
+        globals.set_item("DAY4", DAY4).unwrap();
+        globals.set_item("DAY5", DAY5).unwrap();
+        globals.set_item("DAY9", DAY9).unwrap();
+        globals.set_item("DAY10", DAY10).unwrap();
+        let script = CString::new(script).unwrap();
+        py.run(&script, Some(&globals), None).unwrap();
+    });
+}
+
+#[test]
+fn day4_matching_counts() {
+    run_python(
+        "import pyaoc
+assert pyaoc.number_of_matching(DAY4.splitlines()[0]) == 4
+assert pyaoc.matching_counts(DAY4) == [4, 2, 2, 1, 0, 0]
+assert pyaoc.solve_day4(DAY4) == (13, 30)",
+    );
+}
+
+#[test]
+fn day5_location_ranges() {
+    run_python(
+        "import pyaoc
+assert pyaoc.solve_day5(DAY5) == (35, 46)
+ranges = pyaoc.location_ranges(DAY5)
+assert all(isinstance(r, pyaoc.Range) for r in ranges)
+assert min(r.begin for r in ranges) == 46
+assert sum(len(r) for r in ranges) == 14 + 13",
+    );
+}
+
+#[test]
+fn range_rejects_end_before_begin() {
+    run_python(
+        "import pyaoc
+assert len(pyaoc.Range(3, 3)) == 0
+assert len(pyaoc.Range(3, 5)) == 2
+try:
+    pyaoc.Range(5, 3)
+except ValueError as err:
+    assert str(err) == 'Range end 3 is before its begin 5'
+else:
+    raise AssertionError('Range(5, 3) was accepted')",
+    );
+}
+
+#[test]
+fn day9_extrapolation() {
+    run_python(
+        "import pyaoc
+assert pyaoc.next_value([10, 13, 16, 21, 30, 45]) == 68
+assert pyaoc.previous_value([10, 13, 16, 21, 30, 45]) == 5
+assert pyaoc.solve_day9(DAY9) == (114, 2)",
+    );
+}
+
+#[test]
+fn day10_boundary() {
+    run_python(
+        "import pyaoc
+boundary = pyaoc.find_boundary(DAY10)
+assert boundary[0] == pyaoc.Point(2, 0)
+assert boundary[1] == pyaoc.Point(2, 1)
+assert len(boundary) == 17
+assert not pyaoc.is_tile_inside(pyaoc.Point(3, 3), boundary)
+assert pyaoc.solve_day10(DAY10) == (8, 1)",
+    );
+}
@@ -0,0 +1,92 @@
+use day10::Point;
+
+use crate::{Color, Frame, Recorder, Visualize};
+
+pub struct PipeMaze {
+    map: Vec<Vec<char>>,
+    boundary: Vec<Point>,
+}
+
+impl PipeMaze {
+    pub fn new(contents: &str) -> PipeMaze {
+        let map = day10::parse_input(contents);
+        let boundary = day10::find_boundary(&map);
+        PipeMaze { map, boundary }
+    }
+
+    fn empty_frame(&self) -> Frame {
+        let mut frame = Frame::new(self.map[0].len(), self.map.len());
+        for (x, row) in self.map.iter().enumerate() {
+            for (y, c) in row.iter().enumerate() {
+                if *c != '.' {
+                    frame.set(y, x, Color::Pipe);
+                }
+            }
+        }
+        frame
+    }
+}
+
+impl Visualize for PipeMaze {
+    // Traces the main loop from `S`, then classifies every other tile with `is_tile_inside`.
+    fn visualize(&self, recorder: &mut Recorder) {
+        let mut frame = self.empty_frame();
+        let mut on_loop = vec![vec![false; frame.width]; frame.height];
+
+        for (i, point) in self.boundary.iter().enumerate() {
+            if i > 0 {
+                let prev = &self.boundary[i - 1];
+                frame.set(prev.y, prev.x, Color::Path);
+            }
+            frame.set(point.y, point.x, Color::Head);
+            on_loop[point.x][point.y] = true;
+            recorder.record(&frame);
+        }
+        let last = self.boundary.last().unwrap();
+        frame.set(last.y, last.x, Color::Path);
+
+        for (x, row) in on_loop.iter().enumerate() {
+            for (y, tile_on_loop) in row.iter().enumerate() {
+                if *tile_on_loop {
+                    continue;
+                }
+                let color = if day10::is_tile_inside(&Point { x, y }, &self.boundary) {
+                    Color::Inside
+                } else {
+                    Color::Outside
+                };
+                frame.set(y, x, color);
+                recorder.record(&frame);
+            }
+        }
+
+        recorder.finish(&frame);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_then_fill() {
+        let maze = PipeMaze::new("...........\n.S-------7.\n.|F-----7|.\n.||.....||.\n.||.....||.\n.|L-7.F-J|.\n.|..|.|..|.\n.L--J.L--J.\n...........");
+        let mut recorder = Recorder::new(1);
+        maze.visualize(&mut recorder);
+        let frames = recorder.frames();
+
+        let first = &frames[0];
+        assert_eq!((first.width, first.height), (11, 9));
+        assert_eq!(first.get(1, 1), Color::Head);
+        assert_eq!(first.get(2, 1), Color::Pipe);
+
+        let last = frames.last().unwrap();
+        assert_eq!(last.get(1, 1), Color::Path);
+        assert_eq!(
+            last.cells.iter().filter(|c| **c == Color::Inside).count(),
+            4
+        );
+        assert_eq!(last.get(2, 6), Color::Inside);
+        assert_eq!(last.get(0, 0), Color::Outside);
+    }
+}
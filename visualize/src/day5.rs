@@ -0,0 +1,93 @@
+use day5::Range;
+
+use crate::{Frame, Recorder, Visualize, SEED_COLORS};
+
+const BAND_HEIGHT: usize = 4;
+
+pub struct SeedRanges {
+    stages: Vec<Vec<Vec<Range>>>,
+    width: usize,
+}
+
+impl SeedRanges {
+    pub fn new(contents: &str, width: usize) -> SeedRanges {
+        SeedRanges {
+            stages: day5::calc_stage_ranges(contents),
+            width,
+        }
+    }
+}
+
+impl Visualize for SeedRanges {
+    // One horizontal band per stage (seeds, then after each map), adding one band per frame.
+    // Ranges keep the color of the seed range they were split from.
+    fn visualize(&self, recorder: &mut Recorder) {
+        let stage_count = self.stages.first().map_or(0, |s| s.len());
+        // At least 1, so inputs where every range ends at 0 still scale.
+        let max = self
+            .stages
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|r| r.end)
+            .max()
+            .unwrap_or(0)
+            .max(1) as u128;
+
+        let mut frame = Frame::new(self.width, stage_count * (BAND_HEIGHT + 1));
+        for stage in 0..stage_count {
+            for (seed, stages) in self.stages.iter().enumerate() {
+                let color = SEED_COLORS[seed % SEED_COLORS.len()];
+                for range in stages[stage].iter() {
+                    let begin = (range.begin as u128 * self.width as u128 / max) as usize;
+                    let end = (range.end as u128 * self.width as u128 / max) as usize;
+                    for x in begin..end.max(begin + 1).min(self.width) {
+                        for y in 0..BAND_HEIGHT {
+                            frame.set(x, stage * (BAND_HEIGHT + 1) + y, color);
+                        }
+                    }
+                }
+            }
+            recorder.record(&frame);
+        }
+
+        recorder.finish(&frame);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+
+    #[test]
+    fn one_band_per_stage() {
+        let contents = "seeds: 0 4 8 2\n\nseed-to-soil map:\n8 0 2\n\nsoil-to-fertilizer map:\n0 100 1\n\nfertilizer-to-water map:\n0 100 1\n\nwater-to-light map:\n0 100 1\n\nlight-to-temperature map:\n0 100 1\n\ntemperature-to-humidity map:\n0 100 1\n\nhumidity-to-location map:\n0 100 1";
+        let ranges = SeedRanges::new(contents, 10);
+        let mut recorder = Recorder::new(1);
+        ranges.visualize(&mut recorder);
+        let frames = recorder.frames();
+
+        assert_eq!(frames.len(), 8);
+        assert_eq!((frames[0].width, frames[0].height), (10, 40));
+        assert_eq!(frames[0].get(0, 0), Color::Seed1);
+        assert_eq!(frames[0].get(8, 0), Color::Seed2);
+        assert_eq!(frames[0].get(0, 5), Color::Background);
+        // The first half of seed range 0..4 moves on top of seed range 8..10.
+        assert_eq!(frames[1].get(0, 5), Color::Background);
+        assert_eq!(frames[1].get(2, 5), Color::Seed1);
+    }
+
+    #[test]
+    fn empty_ranges_at_zero() {
+        let maps = (0..7).map(|_| "\n\nx map:\n0 100 1").collect::<String>();
+        let ranges = SeedRanges::new(&format!("seeds: 0 0{maps}"), 10);
+        let mut recorder = Recorder::new(1);
+        ranges.visualize(&mut recorder);
+        let frames = recorder.frames();
+
+        assert_eq!(frames.len(), 8);
+        assert_eq!(frames[0].get(0, 0), Color::Seed1);
+        assert_eq!(frames[0].get(1, 0), Color::Background);
+    }
+}
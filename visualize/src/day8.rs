@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use day8::Net;
+
+use crate::{Color, Frame, Recorder, Visualize};
+
+pub struct Network {
+    starts: Vec<String>,
+    instructions: Vec<char>,
+    map: HashMap<String, Net>,
+    steps: usize,
+}
+
+impl Network {
+    pub fn new(contents: &str, steps: usize) -> Network {
+        let (starts, instructions, map) = day8::parse_input(contents);
+        Network {
+            starts,
+            instructions,
+            map,
+            steps,
+        }
+    }
+}
+
+impl Visualize for Network {
+    // Lays the nodes out on a square grid in name order and moves every ghost one
+    // instruction per frame, leaving a trail of visited nodes behind.
+    fn visualize(&self, recorder: &mut Recorder) {
+        let mut nodes = self.map.keys().collect::<Vec<_>>();
+        nodes.sort();
+        let side = (nodes.len() as f64).sqrt().ceil() as usize;
+        let cells = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.as_str(), (i % side, i / side)))
+            .collect::<HashMap<_, _>>();
+
+        let mut frame = Frame::new(side, side);
+        for (node, (x, y)) in cells.iter() {
+            let color = if node.ends_with('Z') {
+                Color::Target
+            } else {
+                Color::Pipe
+            };
+            frame.set(*x, *y, color);
+        }
+
+        let mut ghosts = self.starts.clone();
+        for step in 0..=self.steps {
+            let mut current = frame.clone();
+            for ghost in ghosts.iter() {
+                let (x, y) = cells[ghost.as_str()];
+                current.set(x, y, Color::Head);
+                if !ghost.ends_with('Z') {
+                    frame.set(x, y, Color::Path);
+                }
+            }
+            recorder.record(&current);
+            if step == self.steps {
+                recorder.finish(&current);
+                break;
+            }
+
+            let instruction = self.instructions[step % self.instructions.len()];
+            ghosts = ghosts
+                .iter()
+                .map(|ghost| day8::jump_one_step(ghost, instruction, &self.map))
+                .collect::<Vec<_>>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ghosts_walk_the_network() {
+        let contents = "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n22A = (22B, XXX)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)\nXXX = (XXX, XXX)";
+        let network = Network::new(contents, 3);
+        let mut recorder = Recorder::new(1);
+        network.visualize(&mut recorder);
+        let frames = recorder.frames();
+
+        assert_eq!(frames.len(), 4);
+        assert_eq!((frames[0].width, frames[0].height), (3, 3));
+        // 11A and 22A are the first and fourth node in name order.
+        assert_eq!(frames[0].get(0, 0), Color::Head);
+        assert_eq!(frames[0].get(0, 1), Color::Head);
+        // After two steps the first ghost stands on 11Z.
+        assert_eq!(frames[2].get(2, 0), Color::Head);
+        assert_eq!(frames[3].get(2, 0), Color::Target);
+        assert_eq!(frames[3].get(0, 0), Color::Path);
+    }
+}
@@ -0,0 +1,109 @@
+pub mod day10;
+pub mod day5;
+pub mod day8;
+pub mod output;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum Color {
+    Background,
+    Pipe,
+    Path,
+    Head,
+    Inside,
+    Outside,
+    Target,
+    Seed1,
+    Seed2,
+    Seed3,
+    Seed4,
+    Seed5,
+}
+
+pub const PALETTE: [[u8; 3]; 12] = [
+    [16, 16, 24],
+    [80, 80, 96],
+    [250, 200, 40],
+    [255, 64, 64],
+    [64, 200, 96],
+    [40, 48, 72],
+    [96, 160, 255],
+    [230, 100, 100],
+    [100, 200, 230],
+    [200, 120, 230],
+    [240, 180, 80],
+    [140, 220, 120],
+];
+
+pub const SEED_COLORS: [Color; 5] = [
+    Color::Seed1,
+    Color::Seed2,
+    Color::Seed3,
+    Color::Seed4,
+    Color::Seed5,
+];
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Frame {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<Color>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize) -> Frame {
+        Frame {
+            width,
+            height,
+            cells: vec![Color::Background; width * height],
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Color {
+        self.cells[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, color: Color) {
+        self.cells[y * self.width + x] = color;
+    }
+}
+
+// Collects the frames a solver emits. Only every `every`-th call to `record` is kept, so a full
+// puzzle input does not produce one frame per step.
+pub struct Recorder {
+    every: usize,
+    ticks: usize,
+    frames: Vec<Frame>,
+}
+
+impl Recorder {
+    pub fn new(every: usize) -> Recorder {
+        Recorder {
+            every: every.max(1),
+            ticks: 0,
+            frames: vec![],
+        }
+    }
+
+    pub fn record(&mut self, frame: &Frame) {
+        if self.ticks.is_multiple_of(self.every) {
+            self.frames.push(frame.clone());
+        }
+        self.ticks += 1;
+    }
+
+    // Always keeps `frame`, so the final state shows up however many steps were skipped.
+    pub fn finish(&mut self, frame: &Frame) {
+        if self.frames.last() != Some(frame) {
+            self.frames.push(frame.clone());
+        }
+    }
+
+    pub fn frames(self) -> Vec<Frame> {
+        self.frames
+    }
+}
+
+pub trait Visualize {
+    fn visualize(&self, recorder: &mut Recorder);
+}
@@ -0,0 +1,149 @@
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+use crate::{Frame, PALETTE};
+
+fn palette() -> Vec<u8> {
+    PALETTE.iter().flatten().copied().collect::<Vec<_>>()
+}
+
+// Image encoders reject empty images, so catch them with a readable error first.
+fn check_size(frame: &Frame, scale: usize) -> Result<(), Box<dyn Error>> {
+    if scale == 0 {
+        return Err("the scale must be at least 1".into());
+    }
+    if frame.width == 0 || frame.height == 0 {
+        return Err(format!("cannot write a {}x{} frame", frame.width, frame.height).into());
+    }
+    Ok(())
+}
+
+fn scale_up(frame: &Frame, scale: usize) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(frame.width * frame.height * scale * scale);
+    for y in 0..frame.height * scale {
+        for x in 0..frame.width * scale {
+            pixels.push(frame.get(x / scale, y / scale) as u8);
+        }
+    }
+    pixels
+}
+
+pub fn write_gif(
+    frames: &[Frame],
+    path: &Path,
+    scale: usize,
+    delay: u16,
+) -> Result<(), Box<dyn Error>> {
+    let first = frames.first().ok_or("no frames to write")?;
+    check_size(first, scale)?;
+    let width = u16::try_from(first.width * scale)?;
+    let height = u16::try_from(first.height * scale)?;
+
+    let file = BufWriter::new(File::create(path)?);
+    let mut encoder = gif::Encoder::new(file, width, height, &palette())?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    for frame in frames {
+        let mut gif_frame =
+            gif::Frame::from_indexed_pixels(width, height, scale_up(frame, scale), None);
+        gif_frame.delay = delay;
+        encoder.write_frame(&gif_frame)?;
+    }
+
+    Ok(())
+}
+
+pub fn write_png_sequence(
+    frames: &[Frame],
+    dir: &Path,
+    scale: usize,
+) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    fs::create_dir_all(dir)?;
+
+    frames
+        .iter()
+        .enumerate()
+        .map(|(i, frame)| {
+            check_size(frame, scale)?;
+            let path = dir.join(format!("frame_{i:05}.png"));
+            let file = BufWriter::new(File::create(&path)?);
+            let mut encoder = png::Encoder::new(
+                file,
+                (frame.width * scale) as u32,
+                (frame.height * scale) as u32,
+            );
+            encoder.set_color(png::ColorType::Indexed);
+            encoder.set_depth(png::BitDepth::Eight);
+            encoder.set_palette(palette());
+            let mut writer = encoder.write_header()?;
+            writer.write_image_data(&scale_up(frame, scale))?;
+            Ok(path)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+
+    fn frames() -> Vec<Frame> {
+        (0..3)
+            .map(|i| {
+                let mut frame = Frame::new(4, 2);
+                frame.set(i, 1, Color::Path);
+                frame
+            })
+            .collect()
+    }
+
+    #[test]
+    fn scale_frame() {
+        let mut frame = Frame::new(2, 1);
+        frame.set(1, 0, Color::Inside);
+        assert_eq!(scale_up(&frame, 2), vec![0, 0, 4, 4, 0, 0, 4, 4]);
+    }
+
+    #[test]
+    fn reject_empty_images() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.gif");
+        let error = write_gif(&frames(), &path, 0, 10).unwrap_err();
+        assert_eq!(error.to_string(), "the scale must be at least 1");
+        let error = write_png_sequence(&[Frame::new(0, 3)], dir.path(), 1).unwrap_err();
+        assert_eq!(error.to_string(), "cannot write a 0x3 frame");
+    }
+
+    #[test]
+    fn gif_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.gif");
+        write_gif(&frames(), &path, 3, 10).unwrap();
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::Indexed);
+        let mut decoder = options.read_info(File::open(&path).unwrap()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (12, 6));
+
+        let mut count = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.buffer[3 * 12 + 3 * count], Color::Path as u8);
+            count += 1;
+        }
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn png_sequence() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = write_png_sequence(&frames(), &dir.path().join("frames"), 1).unwrap();
+        assert_eq!(paths.len(), 3);
+        assert!(paths[2].ends_with("frame_00002.png"));
+
+        let decoder = png::Decoder::new(std::io::BufReader::new(File::open(&paths[1]).unwrap()));
+        let reader = decoder.read_info().unwrap();
+        assert_eq!((reader.info().width, reader.info().height), (4, 2));
+    }
+}